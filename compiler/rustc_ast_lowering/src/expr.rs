@@ -1058,6 +1058,27 @@ fn destructure_assign(
             }
             _ => {}
         }
+        // Reject expressions that can never be assigned to, rather than leaving typeck to report
+        // an error on the desugared assignment.
+        if !matches!(
+            lhs.kind,
+            ExprKind::Path(..)
+                | ExprKind::Field(..)
+                | ExprKind::Index(..)
+                | ExprKind::Unary(UnOp::Deref, _)
+                | ExprKind::Type(..)
+                | ExprKind::Err
+        ) {
+            struct_span_err!(
+                self.sess,
+                lhs.span,
+                E0070,
+                "invalid left-hand side of destructuring assignment: expected a place expression"
+            )
+            .span_label(lhs.span, "cannot assign to this expression")
+            .emit();
+            return self.pat_without_dbm(lhs.span, hir::PatKind::Wild);
+        }
         // Treat all other cases as normal lvalue.
         let ident = Ident::new(sym::lhs, lhs.span);
         let (pat, binding) = self.pat_ident(lhs.span, ident);
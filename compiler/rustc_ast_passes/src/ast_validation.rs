@@ -1123,20 +1123,23 @@ fn visit_generic_args(&mut self, _: Span, generic_args: &'a GenericArgs) {
     }
 
     fn visit_generics(&mut self, generics: &'a Generics) {
-        let mut prev_ty_default = None;
+        let mut prev_param_default = None;
         for param in &generics.params {
             match param.kind {
                 GenericParamKind::Lifetime => (),
                 GenericParamKind::Type { default: Some(_), .. } => {
-                    prev_ty_default = Some(param.ident.span);
+                    prev_param_default = Some((param.ident.span, "type"));
+                }
+                GenericParamKind::Const { default: Some(_), .. } => {
+                    prev_param_default = Some((param.ident.span, "const"));
                 }
                 GenericParamKind::Type { .. } | GenericParamKind::Const { .. } => {
-                    if let Some(span) = prev_ty_default {
+                    if let Some((span, kind)) = prev_param_default {
                         let mut err = self.err_handler().struct_span_err(
                             span,
-                            "type parameters with a default must be trailing",
+                            &format!("{} parameters with a default must be trailing", kind),
                         );
-                        if matches!(param.kind, GenericParamKind::Const { .. }) {
+                        if kind == "type" && matches!(param.kind, GenericParamKind::Const { .. }) {
                             err.note(
                                 "using type defaults and const parameters \
                                  in the same parameter list is currently not permitted",
@@ -1157,7 +1160,7 @@ fn visit_generics(&mut self, generics: &'a Generics) {
                 let (kind, ident) = match &param.kind {
                     GenericParamKind::Lifetime => (ParamKindOrd::Lifetime, ident),
                     GenericParamKind::Type { default: _ } => (ParamKindOrd::Type, ident),
-                    GenericParamKind::Const { ref ty, kw_span: _ } => {
+                    GenericParamKind::Const { ref ty, kw_span: _, default: _ } => {
                         let ty = pprust::ty_to_string(ty);
                         let unordered = self.session.features_untracked().const_generics;
                         (
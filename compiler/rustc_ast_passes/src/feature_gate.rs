@@ -531,13 +531,22 @@ fn visit_fn(&mut self, fn_kind: FnKind<'a>, span: Span, _: NodeId) {
 
     fn visit_generic_param(&mut self, param: &'a GenericParam) {
         if let GenericParamKind::Const { .. } = param.kind {
-            gate_feature_fn!(
-                &self,
-                |x: &Features| x.const_generics || x.min_const_generics,
-                param.ident.span,
-                sym::min_const_generics,
-                "const generics are unstable"
-            );
+            // Only the declaration of a const parameter is gated: const arguments are accepted
+            // without the feature so that crates can use const generic items from their
+            // dependencies.
+            let span = param.ident.span;
+            if !(self.features.const_generics || self.features.min_const_generics)
+                && !span.allows_unstable(sym::min_const_generics)
+            {
+                feature_err(
+                    &self.sess.parse_sess,
+                    sym::min_const_generics,
+                    span,
+                    "const generics are unstable",
+                )
+                .span_label(span, format!("const parameter `{}` declared here", param.ident))
+                .emit();
+            }
         }
         visit::walk_generic_param(self, param)
     }
@@ -630,6 +639,10 @@ macro_rules! gate_all {
     gate_all!(const_trait_impl, "const trait impls are experimental");
     gate_all!(half_open_range_patterns, "half-open range patterns are unstable");
     gate_all!(inline_const, "inline-const is experimental");
+    gate_all!(
+        const_generics_defaults,
+        "default values for const generic parameters are experimental"
+    );
     if sess.parse_sess.span_diagnostic.err_count() == 0 {
         // Errors for `destructuring_assignment` can get quite noisy, especially where `_` is
         // involved, so we only emit errors where there are no other parsing errors.
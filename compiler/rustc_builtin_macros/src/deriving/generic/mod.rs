@@ -560,7 +560,12 @@ fn create_derived_impl(
 
                 cx.typaram(self.span, param.ident, vec![], bounds, None)
             }
-            GenericParamKind::Const { .. } => param.clone(),
+            GenericParamKind::Const { ref ty, kw_span, .. } => {
+                // Impls cannot have defaults for their const parameters.
+                let mut param = param.clone();
+                param.kind = GenericParamKind::Const { ty: ty.clone(), kw_span, default: None };
+                param
+            }
         }));
 
         // and similarly for where clauses
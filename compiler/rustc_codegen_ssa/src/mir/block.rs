@@ -938,6 +938,8 @@ pub fn codegen_block(&mut self, bb: mir::BasicBlock) {
 
         debug!("codegen_block({:?}={:?})", bb, data);
 
+        self.place_cache.clear();
+
         for statement in &data.statements {
             bx = self.codegen_statement(bx, statement);
         }
@@ -1,5 +1,6 @@
 use crate::base;
 use crate::traits::*;
+use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::ErrorReported;
 use rustc_middle::mir;
 use rustc_middle::mir::interpret::ErrorHandled;
@@ -84,6 +85,12 @@ pub struct FunctionCx<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> {
 
     /// Caller location propagated if this function has `#[track_caller]`.
     caller_location: Option<OperandRef<'tcx, Bx::Value>>,
+
+    /// Places assigned to in the current basic block, so that repeated assignments
+    /// to the same place don't recompute its address. Only places that are field
+    /// projections of a `LocalRef::Place` are cached, as their address cannot change
+    /// within a function; the cache is nonetheless cleared at the start of each block.
+    place_cache: FxHashMap<mir::Place<'tcx>, PlaceRef<'tcx, Bx::Value>>,
 }
 
 impl<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> FunctionCx<'a, 'tcx, Bx> {
@@ -184,6 +191,7 @@ pub fn codegen_mir<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
         debug_context,
         per_local_var_debug_info: None,
         caller_location: None,
+        place_cache: Default::default(),
     };
 
     fx.per_local_var_debug_info = fx.compute_per_local_var_debug_info();
@@ -4,6 +4,7 @@
 use super::FunctionCx;
 use super::LocalRef;
 use super::OperandValue;
+use super::PlaceRef;
 use crate::traits::BuilderMethods;
 use crate::traits::*;
 
@@ -28,20 +29,32 @@ pub fn codegen_statement(&mut self, mut bx: Bx, statement: &mir::Statement<'tcx>
                         }
                         LocalRef::Operand(Some(op)) => {
                             if !op.layout.is_zst() {
-                                span_bug!(
+                                // Only ZST operand locals can be assigned more than once, so
+                                // the MIR is malformed. Skip the assignment and let the delayed
+                                // bug fail the compilation, pointing at the statement.
+                                bx.sess().delay_span_bug(
                                     statement.source_info.span,
-                                    "operand {:?} already assigned",
-                                    rvalue
+                                    &format!(
+                                        "operand local {:?} already assigned: {:?}",
+                                        index, rvalue
+                                    ),
                                 );
+                                return bx;
                             }
 
                             // If the type is zero-sized, it's already been set here,
-                            // but we still need to make sure we codegen the operand
-                            self.codegen_rvalue_operand(bx, rvalue).0
+                            // but we still need to make sure we codegen the operand,
+                            // unless it is a constant: those were evaluated up front and
+                            // produce the same (empty) value every time.
+                            if let mir::Rvalue::Use(mir::Operand::Constant(_)) = rvalue {
+                                bx
+                            } else {
+                                self.codegen_rvalue_operand(bx, rvalue).0
+                            }
                         }
                     }
                 } else {
-                    let cg_dest = self.codegen_place(&mut bx, place.as_ref());
+                    let cg_dest = self.codegen_assign_dest(&mut bx, *place);
                     self.codegen_rvalue(bx, cg_dest, rvalue)
                 }
             }
@@ -67,6 +80,14 @@ pub fn codegen_statement(&mut self, mut bx: Bx, statement: &mir::Statement<'tcx>
                 bx
             }
             mir::StatementKind::LlvmInlineAsm(ref asm) => {
+                // Catch MIR building bugs here, before LLVM complains about the constraint
+                // string in a confusing way.
+                debug_assert!(
+                    asm.operand_counts_match(),
+                    "inline asm operand counts do not match its template: {:?}",
+                    asm,
+                );
+
                 let outputs = asm
                     .outputs
                     .iter()
@@ -121,4 +142,24 @@ pub fn codegen_statement(&mut self, mut bx: Bx, statement: &mir::Statement<'tcx>
             | mir::StatementKind::Nop => bx,
         }
     }
+
+    /// Computes the destination of an assignment, reusing the address computed for an
+    /// earlier assignment to the same place in this basic block where that is sound.
+    fn codegen_assign_dest(
+        &mut self,
+        bx: &mut Bx,
+        place: mir::Place<'tcx>,
+    ) -> PlaceRef<'tcx, Bx::Value> {
+        let cacheable = matches!(self.locals[place.local], LocalRef::Place(_))
+            && place.projection.iter().all(|elem| matches!(elem, mir::ProjectionElem::Field(..)));
+        if !cacheable {
+            return self.codegen_place(bx, place.as_ref());
+        }
+        if let Some(&cg_dest) = self.place_cache.get(&place) {
+            return cg_dest;
+        }
+        let cg_dest = self.codegen_place(bx, place.as_ref());
+        self.place_cache.insert(place, cg_dest);
+        cg_dest
+    }
 }
@@ -619,6 +619,9 @@ pub fn set(&self, features: &mut Features, span: Span) {
     /// Allows capturing disjoint fields in a closure/generator (RFC 2229).
     (active, capture_disjoint_fields, "1.49.0", Some(53488), None),
 
+    /// Allows const generic parameters to have a default value.
+    (active, const_generics_defaults, "1.50.0", Some(44580), None),
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
@@ -643,6 +646,7 @@ pub fn set(&self, features: &mut Features, span: Span) {
     sym::repr128,
     sym::unsized_locals,
     sym::capture_disjoint_fields,
+    sym::const_generics_defaults,
 ];
 
 /// Some features are not allowed to be used together at the same time, if
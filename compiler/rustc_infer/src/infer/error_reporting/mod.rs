@@ -939,33 +939,25 @@ fn strip_generic_default_params(
     ) -> SubstsRef<'tcx> {
         let generics = self.tcx.generics_of(def_id);
         let mut num_supplied_defaults = 0;
-        let mut type_params = generics
+        let non_lifetime_params = generics
             .params
             .iter()
             .rev()
-            .filter_map(|param| match param.kind {
-                ty::GenericParamDefKind::Lifetime => None,
-                ty::GenericParamDefKind::Type { has_default, .. } => {
-                    Some((param.def_id, has_default))
+            .filter(|param| !matches!(param.kind, ty::GenericParamDefKind::Lifetime));
+        for param in non_lifetime_params {
+            let default: ty::subst::GenericArg<'tcx> = match param.kind {
+                ty::GenericParamDefKind::Type { has_default: true, .. } => {
+                    self.tcx.type_of(param.def_id).into()
                 }
-                ty::GenericParamDefKind::Const => None, // FIXME(const_generics:defaults)
-            })
-            .peekable();
-        let has_default = {
-            let has_default = type_params.peek().map(|(_, has_default)| has_default);
-            *has_default.unwrap_or(&false)
-        };
-        if has_default {
-            let types = substs.types().rev();
-            for ((def_id, has_default), actual) in type_params.zip(types) {
-                if !has_default {
-                    break;
+                ty::GenericParamDefKind::Const { has_default: true } => {
+                    self.tcx.const_param_default(param.def_id).into()
                 }
-                if self.tcx.type_of(def_id).subst(self.tcx, substs) != actual {
-                    break;
-                }
-                num_supplied_defaults += 1;
+                _ => break,
+            };
+            if default.subst(self.tcx, substs) != substs[param.index as usize] {
+                break;
             }
+            num_supplied_defaults += 1;
         }
         let len = generics.params.len();
         let mut generics = generics.clone();
@@ -1147,8 +1139,8 @@ fn push_ty_ref<'tcx>(
                     let len1 = sub_no_defaults_1.len();
                     let len2 = sub_no_defaults_2.len();
                     let common_len = cmp::min(len1, len2);
-                    let remainder1: Vec<_> = sub1.types().skip(common_len).collect();
-                    let remainder2: Vec<_> = sub2.types().skip(common_len).collect();
+                    let remainder1: Vec<_> = sub1.iter().skip(common_len).collect();
+                    let remainder2: Vec<_> = sub2.iter().skip(common_len).collect();
                     let common_default_params = remainder1
                         .iter()
                         .rev()
@@ -1156,7 +1148,11 @@ fn push_ty_ref<'tcx>(
                         .filter(|(a, b)| a == b)
                         .count();
                     let len = sub1.len() - common_default_params;
-                    let consts_offset = len - sub1.consts().count();
+                    // Const parameters come last, so their defaults are elided first.
+                    let num_consts = sub1.consts().count();
+                    let num_display_consts =
+                        num_consts - cmp::min(num_consts, common_default_params);
+                    let consts_offset = len - num_display_consts;
 
                     // Only draw `<...>` if there're lifetime/type arguments.
                     if len > 0 {
@@ -1216,7 +1212,7 @@ fn lifetime_display(lifetime: Region<'_>) -> String {
                     // Do the same for const arguments, if they are equal, do not highlight and
                     // elide them from the output.
                     let const_arguments = sub1.consts().zip(sub2.consts());
-                    for (i, (ca1, ca2)) in const_arguments.enumerate() {
+                    for (i, (ca1, ca2)) in const_arguments.take(num_display_consts).enumerate() {
                         let i = i + consts_offset;
                         if ca1 == ca2 {
                             values.0.push_normal("_");
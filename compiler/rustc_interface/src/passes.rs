@@ -868,6 +868,7 @@ fn analysis(tcx: TyCtxt<'_>, cnum: CrateNum) -> Result<()> {
     });
 
     sess.time("layout_testing", || layout_test::test_layout(tcx));
+    sess.time("vtable_testing", || mir::const_eval::test_vtables(tcx));
 
     // Avoid overwhelming user with errors if borrow checking failed.
     // I'm not sure how helpful this is, to be honest, but it avoids a
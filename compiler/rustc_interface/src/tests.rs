@@ -7,7 +7,7 @@
 use rustc_session::config::{rustc_optgroups, ErrorOutputType, ExternLocation, Options, Passes};
 use rustc_session::config::{CFGuard, ExternEntry, LinkerPluginLto, LtoCli, SwitchWithOptPath};
 use rustc_session::config::{
-    Externs, OutputType, OutputTypes, SanitizerSet, SymbolManglingVersion,
+    ConstGenericOverflow, Externs, OutputType, OutputTypes, SanitizerSet, SymbolManglingVersion,
 };
 use rustc_session::lint::Level;
 use rustc_session::search_paths::SearchPath;
@@ -471,6 +471,7 @@ macro_rules! untracked {
     untracked!(deduplicate_diagnostics, true);
     untracked!(dep_tasks, true);
     untracked!(dont_buffer_diagnostics, true);
+    untracked!(dump_const_arg_values, true);
     untracked!(dump_dep_graph, true);
     untracked!(dump_mir, Some(String::from("abc")));
     untracked!(dump_mir_dataflow, true);
@@ -543,6 +544,8 @@ macro_rules! tracked {
     tracked!(binary_dep_depinfo, true);
     tracked!(chalk, true);
     tracked!(codegen_backend, Some("abc".to_string()));
+    tracked!(const_eval_max_vtables, 42);
+    tracked!(const_generic_overflow, ConstGenericOverflow::Wrap);
     tracked!(crate_attr, vec!["abc".to_string()]);
     tracked!(debug_macros, true);
     tracked!(dep_info_omit_d_target, true);
@@ -587,6 +590,7 @@ macro_rules! tracked {
     tracked!(share_generics, Some(true));
     tracked!(show_span, Some(String::from("abc")));
     tracked!(src_hash_algorithm, Some(SourceFileHashAlgorithm::Sha1));
+    tracked!(strict_unused_lifetimes, true);
     tracked!(symbol_mangling_version, SymbolManglingVersion::V0);
     tracked!(teach, true);
     tracked!(thinlto, Some(true));
@@ -595,6 +599,7 @@ macro_rules! tracked {
     tracked!(treat_err_as_bug, Some(1));
     tracked!(unleash_the_miri_inside_of_you, true);
     tracked!(use_ctors_section, Some(true));
+    tracked!(validate_vtables, true);
     tracked!(verify_llvm_ir, true);
 }
 
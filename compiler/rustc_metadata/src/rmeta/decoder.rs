@@ -1227,6 +1227,15 @@ fn get_mir_abstract_const(
             .map_or(Ok(None), |v| Ok(Some(v.decode((self, tcx)))))
     }
 
+    fn get_const_param_default(&self, tcx: TyCtxt<'tcx>, id: DefIndex) -> ty::Const<'tcx> {
+        self.root
+            .tables
+            .const_defaults
+            .get(self, id)
+            .unwrap_or_else(|| panic!("Not a const param with a default: {:?}", id))
+            .decode((self, tcx))
+    }
+
     fn get_unused_generic_params(&self, id: DefIndex) -> FiniteBitSet<u32> {
         self.root
             .tables
@@ -1802,13 +1802,14 @@ fn encode_info_for_generics(&mut self, generics: &hir::Generics<'tcx>) {
                         self.encode_stability(def_id.to_def_id());
                     }
                 }
-                GenericParamKind::Const { .. } => {
-                    self.encode_info_for_generic_param(
-                        def_id.to_def_id(),
-                        EntryKind::ConstParam,
-                        true,
-                    );
-                    // FIXME(const_generics:defaults)
+                GenericParamKind::Const { ref default, .. } => {
+                    let def_id = def_id.to_def_id();
+                    self.encode_info_for_generic_param(def_id, EntryKind::ConstParam, true);
+                    if default.is_some() {
+                        self.encode_stability(def_id);
+                        let default = self.tcx.const_param_default(def_id);
+                        record!(self.tables.const_defaults[def_id] <- default);
+                    }
                 }
             }
         }
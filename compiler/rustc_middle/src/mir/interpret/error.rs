@@ -208,6 +208,12 @@ pub enum UndefinedBehaviorInfo<'tcx> {
     InvalidMeta(&'static str),
     /// Invalid drop function in vtable.
     InvalidDropFn(FnSig<'tcx>),
+    /// Invalid vtable: size is bigger than the largest supported object.
+    InvalidVtableSize,
+    /// Invalid vtable: alignment is not a power of 2.
+    InvalidVtableAlignment(String),
+    /// Invalid vtable: the allocation does not have the shape of a vtable.
+    InvalidVtableLayout(String),
     /// Reading a C string that does not end within its allocation.
     UnterminatedCString(Pointer),
     /// Dereferencing a dangling pointer after it got freed.
@@ -272,6 +278,11 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 "invalid drop function signature: got {}, expected exactly one argument which must be a pointer type",
                 sig
             ),
+            InvalidVtableSize => {
+                write!(f, "invalid vtable: size is bigger than largest supported object")
+            }
+            InvalidVtableAlignment(msg) => write!(f, "invalid vtable: alignment {}", msg),
+            InvalidVtableLayout(msg) => write!(f, "invalid vtable: {}", msg),
             UnterminatedCString(p) => write!(
                 f,
                 "reading a null-terminated string starting at {} with no null found before end of allocation",
@@ -387,6 +398,10 @@ pub enum ResourceExhaustionInfo {
     ///
     /// The exact limit is set by the `const_eval_limit` attribute.
     StepLimitReached,
+    /// The program created too many distinct vtables.
+    ///
+    /// The exact limit is set by `-Z const-eval-max-vtables`.
+    VtableLimitReached,
 }
 
 impl fmt::Display for ResourceExhaustionInfo {
@@ -399,6 +414,10 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             StepLimitReached => {
                 write!(f, "exceeded interpreter step limit (see `#[const_eval_limit]`)")
             }
+            VtableLimitReached => write!(
+                f,
+                "reached the configured maximum number of vtables (see `-Z const-eval-max-vtables`)"
+            ),
         }
     }
 }
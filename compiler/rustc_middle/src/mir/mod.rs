@@ -1558,6 +1558,15 @@ pub struct LlvmInlineAsm<'tcx> {
     pub inputs: Box<[(Span, Operand<'tcx>)]>,
 }
 
+impl LlvmInlineAsm<'_> {
+    /// Returns `true` if there is exactly one output and one input operand for each output and
+    /// input constraint in the template. They can only get out of sync through a bug in MIR
+    /// building.
+    pub fn operand_counts_match(&self) -> bool {
+        self.outputs.len() == self.asm.outputs.len() && self.inputs.len() == self.asm.inputs.len()
+    }
+}
+
 impl Debug for Statement<'_> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
         use self::StatementKind::*;
@@ -2621,3 +2630,6 @@ pub fn dominates(&self, other: Location, dominators: &Dominators<BasicBlock>) ->
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
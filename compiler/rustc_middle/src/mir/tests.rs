@@ -0,0 +1,46 @@
+use super::*;
+
+use rustc_ast::{LlvmAsmDialect, StrStyle};
+use rustc_span::with_default_session_globals;
+
+/// Builds an inline asm statement whose template has `template_outputs` outputs and
+/// `template_inputs` inputs, but which has `outputs` output and `inputs` input operands.
+fn llvm_asm(
+    template_outputs: usize,
+    template_inputs: usize,
+    outputs: usize,
+    inputs: usize,
+) -> LlvmInlineAsm<'static> {
+    let place = Place { local: Local::from_u32(0), projection: List::empty() };
+    LlvmInlineAsm {
+        asm: hir::LlvmInlineAsmInner {
+            asm: Symbol::intern("nop"),
+            asm_str_style: StrStyle::Cooked,
+            outputs: (0..template_outputs)
+                .map(|_| hir::LlvmInlineAsmOutput {
+                    constraint: Symbol::intern("=r"),
+                    is_rw: false,
+                    is_indirect: false,
+                    span: DUMMY_SP,
+                })
+                .collect(),
+            inputs: (0..template_inputs).map(|_| Symbol::intern("r")).collect(),
+            clobbers: vec![],
+            volatile: false,
+            alignstack: false,
+            dialect: LlvmAsmDialect::Att,
+        },
+        outputs: vec![place; outputs].into_boxed_slice(),
+        inputs: (0..inputs).map(|_| (DUMMY_SP, Operand::Copy(place))).collect(),
+    }
+}
+
+#[test]
+fn test_llvm_asm_operand_counts() {
+    with_default_session_globals(|| {
+        assert!(llvm_asm(0, 0, 0, 0).operand_counts_match());
+        assert!(llvm_asm(1, 2, 1, 2).operand_counts_match());
+        assert!(!llvm_asm(1, 0, 2, 0).operand_counts_match());
+        assert!(!llvm_asm(0, 2, 0, 1).operand_counts_match());
+    });
+}
@@ -118,6 +118,12 @@ fn describe_as_module(def_id: LocalDefId, tcx: TyCtxt<'_>) -> String {
             cache_on_disk_if { key.is_local() }
         }
 
+        /// Computes the default value of a const parameter which has one,
+        /// e.g. `3` for `N` in `struct Foo<const N: usize = 3>`.
+        query const_param_default(param: DefId) -> &'tcx ty::Const<'tcx> {
+            desc { |tcx| "computing the default value of const parameter `{}`", tcx.def_path_str(param) }
+        }
+
         query analysis(key: CrateNum) -> Result<(), ErrorReported> {
             eval_always
             desc { "running analysis passes on this crate" }
@@ -8,8 +8,10 @@
 use rustc_data_structures::fx::FxHashMap;
 
 use rustc_ast::Mutability;
+use rustc_hir::def::DefKind;
 use rustc_hir::def_id::DefId;
 use rustc_middle::mir::AssertMessage;
+use rustc_session::config::ConstGenericOverflow;
 use rustc_session::Limit;
 use rustc_span::symbol::{sym, Symbol};
 
@@ -21,6 +23,15 @@
 use super::error::*;
 
 impl<'mir, 'tcx> InterpCx<'mir, 'tcx, CompileTimeInterpreter<'mir, 'tcx>> {
+    /// Whether arithmetic overflow should wrap around instead of being an error. This is only
+    /// the case for const arguments and array lengths with `-Z const-generic-overflow=wrap`.
+    fn wrap_const_generic_overflow(&self) -> bool {
+        self.tcx.sess.opts.debugging_opts.const_generic_overflow == ConstGenericOverflow::Wrap
+            && self.stack().first().map_or(false, |frame| {
+                self.tcx.def_kind(frame.instance.def_id()) == DefKind::AnonConst
+            })
+    }
+
     /// Evaluate a const function where all arguments (if any) are zero-sized types.
     /// The evaluation is memoized thanks to the query system.
     ///
@@ -330,6 +341,25 @@ fn assert_panic(
             ResumedAfterReturn(generator_kind) => ResumedAfterReturn(*generator_kind),
             ResumedAfterPanic(generator_kind) => ResumedAfterPanic(*generator_kind),
         };
+        if let Overflow(..) | OverflowNeg(..) = err {
+            if ecx.wrap_const_generic_overflow() {
+                ecx.tcx.sess.span_warn(
+                    ecx.cur_span(),
+                    &format!(
+                        "{:?}; wrapping around because of `-Z const-generic-overflow=wrap`",
+                        err
+                    ),
+                );
+                let frame = ecx.frame();
+                let loc = frame.current_loc().expect("evaluating an `Assert` without a location");
+                let target = match frame.body[loc.block].terminator().kind {
+                    mir::TerminatorKind::Assert { target, .. } => target,
+                    ref kind => bug!("overflow assertion failed in {:?}", kind),
+                };
+                ecx.go_to_block(target);
+                return Ok(());
+            }
+        }
         Err(ConstEvalErrKind::AssertFailure(err).into())
     }
 
@@ -0,0 +1,359 @@
+use rustc_ast::Attribute;
+use rustc_data_structures::sync::Lrc;
+use rustc_hir as hir;
+use rustc_hir::itemlikevisit::ItemLikeVisitor;
+use rustc_middle::mir::interpret::{InterpResult, Scalar};
+use rustc_middle::ty::{self, Instance, TyCtxt};
+use rustc_span::symbol::{sym, Symbol};
+
+use super::{mk_eval_cx, CompileTimeEvalContext};
+
+/// Runs the vtable helpers of the interpreter on the trait objects that constants marked with
+/// `#[rustc_dump_vtable(..)]` evaluate to, and reports the results as errors.
+pub fn test_vtables(tcx: TyCtxt<'_>) {
+    if tcx.features().rustc_attrs {
+        // if the `rustc_attrs` feature is not enabled, don't bother testing vtables
+        tcx.hir().krate().visit_all_item_likes(&mut VtableTest { tcx });
+    }
+}
+
+struct VtableTest<'tcx> {
+    tcx: TyCtxt<'tcx>,
+}
+
+impl ItemLikeVisitor<'tcx> for VtableTest<'tcx> {
+    fn visit_item(&mut self, item: &'tcx hir::Item<'tcx>) {
+        if let hir::ItemKind::Const(..) = item.kind {
+            let item_def_id = self.tcx.hir().local_def_id(item.hir_id);
+            for attr in self.tcx.get_attrs(item_def_id.to_def_id()).iter() {
+                if self.tcx.sess.check_name(attr, sym::rustc_dump_vtable) {
+                    self.dump_vtables_of(item, attr);
+                }
+            }
+        }
+    }
+
+    fn visit_trait_item(&mut self, _: &'tcx hir::TraitItem<'tcx>) {}
+    fn visit_impl_item(&mut self, _: &'tcx hir::ImplItem<'tcx>) {}
+}
+
+/// A trait object reference that a constant evaluated to.
+struct TraitObject<'tcx> {
+    data: Scalar,
+    vtable: Scalar,
+    principal: Option<ty::PolyExistentialTraitRef<'tcx>>,
+}
+
+impl VtableTest<'tcx> {
+    fn dump_vtables_of(&self, item: &hir::Item<'tcx>, attr: &Attribute) {
+        let tcx = self.tcx;
+        let def_id = tcx.hir().local_def_id(item.hir_id).to_def_id();
+        let val = match tcx.const_eval_poly(def_id) {
+            Ok(val) => val,
+            // The evaluation error has been reported already.
+            Err(_) => return,
+        };
+        let val = ty::Const::from_value(tcx, val, tcx.type_of(def_id));
+        let mut ecx = mk_eval_cx(tcx, item.span, ty::ParamEnv::reveal_all(), false);
+        let objects = match read_trait_objects(&ecx, val) {
+            Some(objects) => objects,
+            None => {
+                tcx.sess.span_err(
+                    item.span,
+                    "`#[rustc_dump_vtable]` needs a constant that is a reference to a trait \
+                     object, or a tuple of such references",
+                );
+                return;
+            }
+        };
+
+        // Check out the `#[rustc_dump_vtable(..)]` attribute to tell what to dump.
+        let meta_items = attr.meta_item_list().unwrap_or_default();
+        for meta_item in meta_items {
+            let name = meta_item.name_or_empty();
+            let result = match &*name.as_str() {
+                "cache_disabled" => dump_cache_disabled(&mut ecx, &objects),
+                "diff" => dump_diff(&ecx, &objects),
+                "dump" => dump_contents(&ecx, &objects),
+                "equal" => dump_equal(&mut ecx, &objects),
+                "fn_trait" => dump_fn_trait(&mut ecx, &objects),
+                "from_existing" => dump_from_existing(&mut ecx, &objects),
+                "layout" => dump_layout(&mut ecx, &objects),
+                "method_at" => dump_method_at(&ecx, &objects),
+                "method" => match meta_item.value_str() {
+                    Some(method) => dump_method(&ecx, &objects, method),
+                    None => {
+                        tcx.sess.span_err(meta_item.span(), "expected `method = \"name\"`");
+                        continue;
+                    }
+                },
+                _ => {
+                    tcx.sess
+                        .span_err(meta_item.span(), &format!("unrecognized field name `{}`", name));
+                    continue;
+                }
+            };
+            match result {
+                Ok(lines) => {
+                    for line in lines {
+                        tcx.sess.span_err(item.span, &format!("{}: {}", name, line));
+                    }
+                }
+                Err(err) => tcx.sess.span_err(item.span, &format!("{}: {}", name, err)),
+            }
+        }
+    }
+}
+
+/// Reads the trait objects out of the value of a constant, which must be a reference to a trait
+/// object or a tuple of such references.
+fn read_trait_objects(
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
+    val: &'tcx ty::Const<'tcx>,
+) -> Option<Vec<TraitObject<'tcx>>> {
+    let op = ecx.const_to_op(val, None).ok()?;
+    let ops = match val.ty.kind() {
+        ty::Tuple(substs) => {
+            (0..substs.len()).map(|i| ecx.operand_field(op, i).ok()).collect::<Option<_>>()?
+        }
+        _ => vec![op],
+    };
+    ops.into_iter()
+        .map(|op| {
+            let principal = match op.layout.ty.builtin_deref(true)?.ty.kind() {
+                ty::Dynamic(predicates, _) => predicates.principal(),
+                _ => return None,
+            };
+            let (data, vtable) = ecx.read_immediate(op).ok()?.to_scalar_pair().ok()?;
+            Some(TraitObject { data, vtable, principal })
+        })
+        .collect()
+}
+
+/// Describes the slots in which the vtables of a pair of trait objects differ.
+fn dump_diff(
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
+    objects: &[TraitObject<'tcx>],
+) -> InterpResult<'tcx, Vec<String>> {
+    let (a, b) = match objects {
+        [a, b] => (a, b),
+        _ => return Ok(vec!["needs a pair of trait objects".to_string()]),
+    };
+    let diff = ecx.vtable_diff(a.vtable, b.vtable)?;
+    if diff.is_empty() {
+        return Ok(vec!["no differences".to_string()]);
+    }
+    Ok(diff.into_iter().map(|(slot, msg)| format!("slot {}: {}", slot, msg)).collect())
+}
+
+/// Finds the method called `method` in the vtable of each trait object, and checks that
+/// `get_vtable_slot` reads the same instance at the index the method is found at.
+fn dump_method(
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
+    objects: &[TraitObject<'tcx>],
+    method: Symbol,
+) -> InterpResult<'tcx, Vec<String>> {
+    objects
+        .iter()
+        .map(|object| {
+            let principal = match object.principal {
+                Some(principal) => principal,
+                None => return Ok("no principal trait".to_string()),
+            };
+            let (_, ty) = ecx.read_drop_type_from_vtable(object.vtable)?;
+            let trait_ref = principal.with_self_ty(*ecx.tcx, ty);
+            Ok(match ecx.resolve_vtable_method_by_name(trait_ref, method)? {
+                Some((idx, instance)) => {
+                    let slot = ecx.get_vtable_slot(object.vtable, idx)?.as_instance()?;
+                    if slot == instance {
+                        format!("`{}` is method {}", instance, idx)
+                    } else {
+                        format!("`{}` is method {}, but that slot holds `{}`", instance, idx, slot)
+                    }
+                }
+                None => format!("`{}` has no slot", method),
+            })
+        })
+        .collect()
+}
+
+/// Creates a vtable for the dynamic type and principal trait of each trait object, with and
+/// without the vtable cache, and reports which of the `Fn` traits `vtable_is_for_fn_trait` says
+/// each of them is for.
+fn dump_fn_trait(
+    ecx: &mut CompileTimeEvalContext<'_, 'tcx>,
+    objects: &[TraitObject<'tcx>],
+) -> InterpResult<'tcx, Vec<String>> {
+    objects
+        .iter()
+        .map(|object| {
+            let (_, ty) = ecx.read_drop_type_from_vtable(object.vtable)?;
+            let cached = ecx.get_vtable(ty, object.principal)?;
+            let uncached =
+                ecx.with_vtable_cache_disabled(|ecx| ecx.get_vtable(ty, object.principal))?;
+            Ok(format!(
+                "{:?}, with the cache disabled: {:?}",
+                ecx.vtable_is_for_fn_trait(cached),
+                ecx.vtable_is_for_fn_trait(uncached),
+            ))
+        })
+        .collect()
+}
+
+/// Hands the vtable and the data pointer of each trait object to
+/// `vtable_from_existing_allocation`, and reports which of them it accepts. For an accepted
+/// vtable, also checks that `get_vtable` reuses it.
+fn dump_from_existing(
+    ecx: &mut CompileTimeEvalContext<'_, 'tcx>,
+    objects: &[TraitObject<'tcx>],
+) -> InterpResult<'tcx, Vec<String>> {
+    let mut lines = vec![];
+    for object in objects {
+        for &(what, ptr) in &[("vtable", object.vtable), ("data", object.data)] {
+            let ptr = ecx.force_ptr(ptr)?;
+            let line = match ecx.vtable_from_existing_allocation(ptr) {
+                Ok(vtable) => {
+                    let (_, ty) = ecx.read_drop_type_from_vtable(vtable.into())?;
+                    if ecx.get_vtable(ty, object.principal)? == vtable {
+                        format!("{} pointer accepted and reused by `get_vtable`", what)
+                    } else {
+                        format!("{} pointer accepted, but not reused by `get_vtable`", what)
+                    }
+                }
+                Err(_) => format!("{} pointer rejected", what),
+            };
+            lines.push(line);
+        }
+    }
+    Ok(lines)
+}
+
+/// Reads back the whole vtable of each trait object with `dump_vtable`.
+fn dump_contents(
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
+    objects: &[TraitObject<'tcx>],
+) -> InterpResult<'tcx, Vec<String>> {
+    objects
+        .iter()
+        .map(|object| {
+            let dump = ecx.dump_vtable(object.vtable)?;
+            let methods: Vec<_> = dump.methods.into_iter().map(describe_slot).collect();
+            Ok(format!(
+                "drop: {}, size: {}, align: {}, methods: [{}]",
+                describe_slot(dump.drop),
+                dump.size,
+                dump.align,
+                methods.join(", ")
+            ))
+        })
+        .collect()
+}
+
+/// Describes the function in a vtable slot.
+fn describe_slot(slot: Option<Instance<'_>>) -> String {
+    match slot {
+        Some(instance) => format!("`{}`", instance),
+        None => "empty".to_string(),
+    }
+}
+
+/// Creates a vtable for the dynamic type and principal trait of each trait object with
+/// `get_vtable_with_layout`, and reports which definitions its method slots hold. Also checks
+/// that asking for the same vtable again returns the layout computed the first time.
+fn dump_layout(
+    ecx: &mut CompileTimeEvalContext<'_, 'tcx>,
+    objects: &[TraitObject<'tcx>],
+) -> InterpResult<'tcx, Vec<String>> {
+    objects
+        .iter()
+        .map(|object| {
+            let (_, ty) = ecx.read_drop_type_from_vtable(object.vtable)?;
+            let (vtable, layout) = ecx.get_vtable_with_layout(ty, object.principal)?;
+            let (again, again_layout) = ecx.get_vtable_with_layout(ty, object.principal)?;
+            let methods: Vec<_> = layout
+                .methods
+                .iter()
+                .map(|method| match *method {
+                    Some(def_id) => format!("`{}`", ecx.tcx.def_path_str(def_id)),
+                    None => "empty".to_string(),
+                })
+                .collect();
+            Ok(format!(
+                "[{}], reused: {}",
+                methods.join(", "),
+                again == vtable && Lrc::ptr_eq(&again_layout, &layout)
+            ))
+        })
+        .collect()
+}
+
+/// Creates vtables for the dynamic type and principal trait of each trait object with and
+/// without the vtable cache, and reports which of them are shared.
+fn dump_cache_disabled(
+    ecx: &mut CompileTimeEvalContext<'_, 'tcx>,
+    objects: &[TraitObject<'tcx>],
+) -> InterpResult<'tcx, Vec<String>> {
+    objects
+        .iter()
+        .map(|object| {
+            let (_, ty) = ecx.read_drop_type_from_vtable(object.vtable)?;
+            let cached = ecx.get_vtable(ty, object.principal)?;
+            let cached_again = ecx.get_vtable(ty, object.principal)?;
+            let (uncached, uncached_again) =
+                ecx.with_vtable_cache_disabled(|ecx| -> InterpResult<'tcx, _> {
+                    Ok((
+                        ecx.get_vtable(ty, object.principal)?,
+                        ecx.get_vtable(ty, object.principal)?,
+                    ))
+                })?;
+            let after = ecx.get_vtable(ty, object.principal)?;
+            Ok(format!(
+                "cached vtables shared: {}, uncached vtables shared: {}, cache kept: {}",
+                cached_again == cached,
+                uncached_again == uncached || uncached == cached,
+                after == cached
+            ))
+        })
+        .collect()
+}
+
+/// Compares the vtables of a pair of trait objects with `vtable_equal`, and compares the vtable
+/// of each trait object with a copy of it that was created with the vtable cache disabled.
+fn dump_equal(
+    ecx: &mut CompileTimeEvalContext<'_, 'tcx>,
+    objects: &[TraitObject<'tcx>],
+) -> InterpResult<'tcx, Vec<String>> {
+    let mut lines = vec![];
+    if let [a, b] = objects {
+        lines.push(format!("pair: {}", ecx.vtable_equal(a.vtable, b.vtable)?));
+    }
+    for object in objects {
+        let (_, ty) = ecx.read_drop_type_from_vtable(object.vtable)?;
+        let copy = ecx.with_vtable_cache_disabled(|ecx| ecx.get_vtable(ty, object.principal))?;
+        lines.push(format!("uncached copy: {}", ecx.vtable_equal(object.vtable, copy.into())?));
+    }
+    Ok(lines)
+}
+
+/// Reads every method slot of the vtable of each trait object with `vtable_method_instance_at`,
+/// and also the first index past the last slot.
+fn dump_method_at(
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
+    objects: &[TraitObject<'tcx>],
+) -> InterpResult<'tcx, Vec<String>> {
+    let mut lines = vec![];
+    for object in objects {
+        let methods = ecx.read_vtable_all_methods(object.vtable)?.len() as u64;
+        for i in 0..=methods {
+            let line = match ecx.vtable_method_instance_at(object.vtable, i) {
+                Ok(Some((def_id, substs))) => {
+                    format!("`{}`", ecx.tcx.def_path_str_with_substs(def_id, substs))
+                }
+                Ok(None) => "empty".to_string(),
+                Err(err) => err.to_string(),
+            };
+            lines.push(format!("{}: {}", i, line));
+        }
+    }
+    Ok(lines)
+}
@@ -286,9 +286,9 @@ fn unsize_into_ptr(
                 let val = self.read_immediate(src)?;
                 self.write_immediate(*val, dest)
             }
-            (_, &ty::Dynamic(ref data, _)) => {
+            (_, &ty::Dynamic(..)) => {
                 // Initial cast from sized to dyn trait
-                let vtable = self.get_vtable(src_pointee_ty, data.principal())?;
+                let vtable = self.vtable_for_dyn_value(source_ty, cast_ty)?;
                 let ptr = self.read_immediate(src)?.to_scalar()?;
                 let val = Immediate::new_dyn_trait(ptr, vtable);
                 self.write_immediate(val, dest)
@@ -4,12 +4,13 @@
 
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
+use rustc_data_structures::sync::Lrc;
 use rustc_hir::{self as hir, def::DefKind, def_id::DefId, definitions::DefPathData};
 use rustc_index::vec::IndexVec;
 use rustc_macros::HashStable;
 use rustc_middle::ich::StableHashingContext;
 use rustc_middle::mir;
-use rustc_middle::mir::interpret::{GlobalId, InterpResult, Pointer, Scalar};
+use rustc_middle::mir::interpret::{AllocId, GlobalId, InterpResult, Pointer, Scalar};
 use rustc_middle::ty::layout::{self, TyAndLayout};
 use rustc_middle::ty::{
     self, query::TyCtxtAt, subst::SubstsRef, ParamEnv, Ty, TyCtxt, TypeFoldable,
@@ -19,7 +20,7 @@
 
 use super::{
     Immediate, MPlaceTy, Machine, MemPlace, MemPlaceMeta, Memory, Operand, Place, PlaceTy,
-    ScalarMaybeUninit, StackPopJump,
+    ScalarMaybeUninit, StackPopJump, VtableLayout,
 };
 use crate::transform::validate::equal_up_to_regions;
 use crate::util::storage::AlwaysLiveLocals;
@@ -44,6 +45,32 @@ pub struct InterpCx<'mir, 'tcx, M: Machine<'mir, 'tcx>> {
     /// A cache for deduplicating vtables
     pub(super) vtables:
         FxHashMap<(Ty<'tcx>, Option<ty::PolyExistentialTraitRef<'tcx>>), Pointer<M::PointerTag>>,
+
+    /// A cache for deduplicating vtables with identical contents, keyed on the drop glue,
+    /// size, align and method instances that get written into them.
+    pub(super) vtable_contents: FxHashMap<
+        (ty::Instance<'tcx>, Size, Align, Vec<Option<ty::Instance<'tcx>>>),
+        Pointer<M::PointerTag>,
+    >,
+
+    /// The layouts of the vtables in `vtable_contents`, computed when they are first added.
+    pub(super) vtable_layouts: FxHashMap<AllocId, Lrc<VtableLayout>>,
+
+    /// Whether `get_vtable` bypasses the caches above, see `with_vtable_cache_disabled`.
+    pub(super) vtable_cache_disabled: bool,
+
+    /// The principal traits of the vtables `get_vtable` created with the cache disabled, which
+    /// are not in `vtables`.
+    pub(super) uncached_vtable_principals:
+        FxHashMap<AllocId, Option<ty::PolyExistentialTraitRef<'tcx>>>,
+
+    /// The number of vtables `get_vtable` created, which `-Z const-eval-max-vtables` bounds.
+    /// Unlike the size of the caches, this also counts vtables created with the cache disabled.
+    pub(super) vtables_created: usize,
+
+    /// The fn-allocs written into vtables, so that every drop glue or method instance gets a
+    /// single fn-alloc however many vtables it appears in.
+    pub(super) vtable_fn_allocs: FxHashMap<ty::Instance<'tcx>, Pointer<M::PointerTag>>,
 }
 
 // The Phantomdata exists to prevent this type from being `Send`. If it were sent across a thread
@@ -226,6 +253,12 @@ pub fn with_extra<Extra>(self, extra: Extra) -> Frame<'mir, 'tcx, Tag, Extra> {
 }
 
 impl<'mir, 'tcx, Tag, Extra> Frame<'mir, 'tcx, Tag, Extra> {
+    /// Return the location of the current instruction, or the span of the frame if we are
+    /// not currently executing a particular instruction.
+    pub fn current_loc(&self) -> Result<mir::Location, Span> {
+        self.loc
+    }
+
     /// Return the `SourceInfo` of the current instruction.
     pub fn current_source_info(&self) -> Option<&mir::SourceInfo> {
         self.loc.ok().map(|loc| self.body.source_info(loc))
@@ -365,6 +398,12 @@ pub fn new(
             param_env,
             memory: Memory::new(tcx, memory_extra),
             vtables: FxHashMap::default(),
+            vtable_contents: FxHashMap::default(),
+            vtable_layouts: FxHashMap::default(),
+            vtable_cache_disabled: false,
+            uncached_vtable_principals: FxHashMap::default(),
+            vtables_created: 0,
+            vtable_fn_allocs: FxHashMap::default(),
         }
     }
 
@@ -3,6 +3,7 @@
 //! and miri.
 
 use std::convert::TryFrom;
+use std::iter;
 
 use rustc_hir::def_id::DefId;
 use rustc_middle::mir::{
@@ -355,6 +356,38 @@ pub fn emulate_intrinsic(
                 let offset_ptr = ptr.ptr_wrapping_signed_offset(offset_bytes, self);
                 self.write_scalar(offset_ptr, dest)?;
             }
+            sym::copy_nonoverlapping => {
+                let elem_layout = self.layout_of(substs.type_at(0))?;
+                let count = self.read_scalar(args[2])?.to_machine_usize(self)?;
+                let size = elem_layout.size.checked_mul(count, self).ok_or_else(|| {
+                    err_ub_format!("overflow computing total size of `copy_nonoverlapping`")
+                })?;
+                let elem_align = elem_layout.align.abi;
+
+                let src = self.read_scalar(args[0])?.check_init()?;
+                let src = self.memory.check_ptr_access(src, size, elem_align)?;
+                let dest = self.read_scalar(args[1])?.check_init()?;
+                let dest = self.memory.check_ptr_access(dest, size, elem_align)?;
+
+                // `check_ptr_access` returns `None` for zero-sized accesses, which copy nothing.
+                if let (Some(src), Some(dest)) = (src, dest) {
+                    self.memory.copy(src, dest, size, /*nonoverlapping*/ true)?;
+                }
+            }
+            sym::write_bytes => {
+                let elem_layout = self.layout_of(substs.type_at(0))?;
+                let byte = self.read_scalar(args[1])?.to_u8()?;
+                let count = self.read_scalar(args[2])?.to_machine_usize(self)?;
+                let size = elem_layout.size.checked_mul(count, self).ok_or_else(|| {
+                    err_ub_format!("overflow computing total size of `write_bytes`")
+                })?;
+
+                let dst = self.read_scalar(args[0])?.check_init()?;
+                // `Memory::write_bytes` only checks that the bytes are in bounds, so check the
+                // alignment required for `T` here.
+                self.memory.check_ptr_access(dst, size, elem_layout.align.abi)?;
+                self.memory.write_bytes(dst, iter::repeat(byte).take(size.bytes_usize()))?;
+            }
             sym::ptr_offset_from => {
                 let a = self.read_immediate(args[0])?.to_scalar()?;
                 let b = self.read_immediate(args[1])?.to_scalar()?;
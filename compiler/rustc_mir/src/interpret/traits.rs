@@ -1,24 +1,109 @@
 use std::convert::TryFrom;
+use std::mem;
 
-use rustc_middle::mir::interpret::{InterpResult, Pointer, PointerArithmetic, Scalar};
+use rustc_ast::Mutability;
+use rustc_data_structures::sync::Lrc;
+use rustc_hir::def_id::DefId;
+use rustc_middle::mir::interpret::{
+    InterpResult, Pointer, PointerArithmetic, Scalar, ScalarMaybeUninit,
+};
+use rustc_middle::ty::subst::SubstsRef;
 use rustc_middle::ty::{self, Instance, Ty};
+use rustc_span::Symbol;
 use rustc_target::abi::{Align, LayoutOf, Size};
 
 use super::util::ensure_monomorphic_enough;
-use super::{FnVal, InterpCx, Machine, MemoryKind};
+use super::{AllocCheck, FnVal, InterpCx, Machine, MemoryKind};
+
+/// The number of pointer-sized words before the first method in a vtable:
+/// the drop function, the size and the alignment.
+pub(crate) const VTABLE_HEADER_WORDS: u64 = 3;
+
+/// The contents of a vtable, as read back from memory by `InterpCx::dump_vtable`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct VtableDump<'tcx> {
+    /// The drop glue, or `None` if the drop slot is empty.
+    pub drop: Option<Instance<'tcx>>,
+    pub size: u64,
+    pub align: u64,
+    /// The methods in vtable order, with `None` for empty slots.
+    pub methods: Vec<Option<Instance<'tcx>>>,
+}
+
+/// Which method slots of a vtable created by `InterpCx::get_vtable_with_layout` are filled in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VtableLayout {
+    /// The definition each method slot was resolved to, in vtable order, with `None` for slots
+    /// that were left empty (e.g. because the method requires `Self: Sized`).
+    pub methods: Vec<Option<DefId>>,
+}
+
+impl VtableLayout {
+    fn new(methods: &[Option<Instance<'_>>]) -> Self {
+        VtableLayout {
+            methods: methods
+                .iter()
+                .map(|method| method.map(|instance| instance.def_id()))
+                .collect(),
+        }
+    }
+}
 
 impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
+    /// The size of the drop/size/align header that precedes the methods of every vtable.
+    #[inline]
+    pub(crate) fn vtable_header_size(&self) -> Size {
+        self.pointer_size() * VTABLE_HEADER_WORDS
+    }
+
+    /// The offset of the drop function in a vtable.
+    pub fn vtable_drop_slot_offset(&self) -> Size {
+        Size::ZERO
+    }
+
+    /// The offset of the size of the dynamic type in a vtable.
+    pub fn vtable_size_slot_offset(&self) -> Size {
+        self.pointer_size()
+    }
+
+    /// The offset of the alignment of the dynamic type in a vtable.
+    pub fn vtable_align_slot_offset(&self) -> Size {
+        self.pointer_size() * 2
+    }
+
+    /// The offset of the method with the given index in a vtable, where index 0 is the first
+    /// method declared in the trait. Errors if the offset does not fit the target's address space.
+    pub fn vtable_method_slot_offset(&self, idx: u64) -> InterpResult<'tcx, Size> {
+        idx.checked_add(VTABLE_HEADER_WORDS)
+            .and_then(|words| self.pointer_size().checked_mul(words, self))
+            .ok_or_else(|| err_ub_format!("vtable method index {} is out of range", idx).into())
+    }
+
     /// Creates a dynamic vtable for the given type and vtable origin. This is used only for
     /// objects.
     ///
     /// The `trait_ref` encodes the erased self type. Hence, if we are
     /// making an object `Foo<Trait>` from a value of type `Foo<T>`, then
     /// `trait_ref` would map `T: Trait`.
+    ///
+    /// Vtables are cached, and only creating a new vtable counts against the
+    /// `-Z const-eval-max-vtables` limit; returning an existing one always succeeds.
     pub fn get_vtable(
         &mut self,
         ty: Ty<'tcx>,
         poly_trait_ref: Option<ty::PolyExistentialTraitRef<'tcx>>,
     ) -> InterpResult<'tcx, Pointer<M::PointerTag>> {
+        Ok(self.get_vtable_with_layout(ty, poly_trait_ref)?.0)
+    }
+
+    /// Like `get_vtable`, but also returns which method slots of the vtable are filled in. The
+    /// layout is computed once when the vtable is created, so this is as cheap as `get_vtable`
+    /// for vtables that already exist.
+    pub fn get_vtable_with_layout(
+        &mut self,
+        ty: Ty<'tcx>,
+        poly_trait_ref: Option<ty::PolyExistentialTraitRef<'tcx>>,
+    ) -> InterpResult<'tcx, (Pointer<M::PointerTag>, Lrc<VtableLayout>)> {
         trace!("get_vtable(trait_ref={:?})", poly_trait_ref);
 
         let (ty, poly_trait_ref) = self.tcx.erase_regions((ty, poly_trait_ref));
@@ -27,12 +112,14 @@ pub fn get_vtable(
         ensure_monomorphic_enough(*self.tcx, ty)?;
         ensure_monomorphic_enough(*self.tcx, poly_trait_ref)?;
 
-        if let Some(&vtable) = self.vtables.get(&(ty, poly_trait_ref)) {
+        if let Some(&vtable) =
+            self.vtables.get(&(ty, poly_trait_ref)).filter(|_| !self.vtable_cache_disabled)
+        {
             // This means we guarantee that there are no duplicate vtables, we will
             // always use the same vtable for the same (Type, Trait) combination.
             // That's not what happens in rustc, but emulating per-crate deduplication
             // does not sound like it actually makes anything any better.
-            return Ok(vtable);
+            return Ok((vtable, self.cached_vtable_layout(vtable)));
         }
 
         let methods = if let Some(poly_trait_ref) = poly_trait_ref {
@@ -45,11 +132,48 @@ pub fn get_vtable(
         };
 
         let layout = self.layout_of(ty)?;
-        assert!(!layout.is_unsized(), "can't create a vtable for an unsized type");
+        if layout.is_unsized() {
+            // Type checking only allows unsizing sized types to trait objects, so this is not
+            // reachable from well-typed code, but don't ICE on whatever MIR we are handed.
+            throw_unsup_format!("cannot create a vtable for the unsized type `{}`", ty);
+        }
         let size = layout.size.bytes();
         let align = layout.align.abi.bytes();
 
         let tcx = *self.tcx;
+        let drop = Instance::resolve_drop_in_place(tcx, ty);
+        let methods = methods
+            .iter()
+            .map(|method| match *method {
+                // resolve for vtable: insert shims where needed
+                Some((def_id, substs)) => {
+                    ty::Instance::resolve_for_vtable(tcx, self.param_env, def_id, substs)
+                        .map(Some)
+                        .ok_or_else(|| err_inval!(TooGeneric).into())
+                }
+                None => Ok(None),
+            })
+            .collect::<InterpResult<'tcx, Vec<_>>>()?;
+
+        // Different `(Type, Trait)` keys can still produce the same vtable contents (e.g. when
+        // the trait refs only differ in how they were written before normalization), so we
+        // also deduplicate on the instances and layout that would be written to memory.
+        let contents = (drop, layout.size, layout.align.abi, methods);
+        if let Some(&vtable) =
+            self.vtable_contents.get(&contents).filter(|_| !self.vtable_cache_disabled)
+        {
+            self.vtables.insert((ty, poly_trait_ref), vtable);
+            return Ok((vtable, self.cached_vtable_layout(vtable)));
+        }
+        let methods = &contents.3;
+
+        // Every vtable we create stays alive until the end of the evaluation, so bound their
+        // number to keep adversarial code from making us allocate without limit.
+        if self.vtables_created >= self.tcx.sess.opts.debugging_opts.const_eval_max_vtables {
+            throw_exhaust!(VtableLimitReached);
+        }
+        self.vtables_created += 1;
+
         let ptr_size = self.pointer_size();
         let ptr_align = tcx.data_layout.pointer_align.abi;
         // /////////////////////////////////////////////////////////////////////////////////////////
@@ -57,34 +181,30 @@ pub fn get_vtable(
         // `get_vtable` in `rust_codegen_llvm/meth.rs`.
         // /////////////////////////////////////////////////////////////////////////////////////////
         let vtable = self.memory.allocate(
-            ptr_size * u64::try_from(methods.len()).unwrap().checked_add(3).unwrap(),
+            ptr_size
+                * u64::try_from(methods.len()).unwrap().checked_add(VTABLE_HEADER_WORDS).unwrap(),
             ptr_align,
             MemoryKind::Vtable,
         );
 
-        let drop = Instance::resolve_drop_in_place(tcx, ty);
-        let drop = self.memory.create_fn_alloc(FnVal::Instance(drop));
+        let drop = self.vtable_fn_alloc(drop);
 
         // No need to do any alignment checks on the memory accesses below, because we know the
         // allocation is correctly aligned as we created it above. Also we're only offsetting by
         // multiples of `ptr_align`, which means that it will stay aligned to `ptr_align`.
+        let drop_ptr = vtable.offset(self.vtable_drop_slot_offset(), &tcx)?;
+        let size_ptr = vtable.offset(self.vtable_size_slot_offset(), &tcx)?;
+        let align_ptr = vtable.offset(self.vtable_align_slot_offset(), &tcx)?;
         let vtable_alloc = self.memory.get_raw_mut(vtable.alloc_id)?;
-        vtable_alloc.write_ptr_sized(&tcx, vtable, drop.into())?;
-
-        let size_ptr = vtable.offset(ptr_size, &tcx)?;
+        vtable_alloc.write_ptr_sized(&tcx, drop_ptr, drop.into())?;
         vtable_alloc.write_ptr_sized(&tcx, size_ptr, Scalar::from_uint(size, ptr_size).into())?;
-        let align_ptr = vtable.offset(ptr_size * 2, &tcx)?;
         vtable_alloc.write_ptr_sized(&tcx, align_ptr, Scalar::from_uint(align, ptr_size).into())?;
 
         for (i, method) in methods.iter().enumerate() {
-            if let Some((def_id, substs)) = *method {
-                // resolve for vtable: insert shims where needed
-                let instance =
-                    ty::Instance::resolve_for_vtable(tcx, self.param_env, def_id, substs)
-                        .ok_or_else(|| err_inval!(TooGeneric))?;
-                let fn_ptr = self.memory.create_fn_alloc(FnVal::Instance(instance));
+            if let Some(instance) = *method {
+                let fn_ptr = self.vtable_fn_alloc(instance);
                 // We cannot use `vtable_allic` as we are creating fn ptrs in this loop.
-                let method_ptr = vtable.offset(ptr_size * (3 + i as u64), &tcx)?;
+                let method_ptr = vtable.offset(self.vtable_method_slot_offset(i as u64)?, &tcx)?;
                 self.memory.get_raw_mut(vtable.alloc_id)?.write_ptr_sized(
                     &tcx,
                     method_ptr,
@@ -93,10 +213,78 @@ pub fn get_vtable(
             }
         }
 
+        debug_assert_eq!(
+            self.vtable_allocation_size(vtable.into())?,
+            ptr_size * (VTABLE_HEADER_WORDS + u64::try_from(methods.len()).unwrap()),
+        );
+        let vtable_layout = Lrc::new(VtableLayout::new(methods));
+        if self.vtable_cache_disabled {
+            self.uncached_vtable_principals.insert(vtable.alloc_id, poly_trait_ref);
+        } else {
+            assert!(self.vtables.insert((ty, poly_trait_ref), vtable).is_none());
+            assert!(self.vtable_contents.insert(contents, vtable).is_none());
+        }
+        assert!(self.vtable_layouts.insert(vtable.alloc_id, vtable_layout.clone()).is_none());
+
+        // This must come after all writes to the vtable above: once the allocation is
+        // immutable, any further write to it is rejected with `WriteToReadOnly`.
         self.memory.mark_immutable(vtable.alloc_id)?;
-        assert!(self.vtables.insert((ty, poly_trait_ref), vtable).is_none());
+        Ok((vtable, vtable_layout))
+    }
 
-        Ok(vtable)
+    /// Returns the fn-alloc to write into a vtable for `instance`. `Memory::create_fn_alloc`
+    /// hands out a fresh fn-alloc for every mention of a generic function, so without this the
+    /// same method would get a new fn-alloc in each vtable it appears in, e.g. once in the vtable
+    /// of a trait and once more in the vtable of each of its subtraits.
+    fn vtable_fn_alloc(&mut self, instance: Instance<'tcx>) -> Pointer<M::PointerTag> {
+        if let Some(&ptr) = self.vtable_fn_allocs.get(&instance) {
+            return ptr;
+        }
+        let ptr = self.memory.create_fn_alloc(FnVal::Instance(instance));
+        self.vtable_fn_allocs.insert(instance, ptr);
+        ptr
+    }
+
+    /// Runs `f` with the vtable cache bypassed: every `get_vtable` call in `f` allocates a new
+    /// vtable, and the vtables created in `f` are not reused afterwards. This lets tests exercise
+    /// vtable creation for types that already have a vtable. The vtables created in `f` still
+    /// count against `-Z const-eval-max-vtables`.
+    pub fn with_vtable_cache_disabled<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        let old = mem::replace(&mut self.vtable_cache_disabled, true);
+        let r = f(self);
+        self.vtable_cache_disabled = old;
+        r
+    }
+
+    /// Returns the layout recorded for a vtable that `get_vtable_with_layout` or
+    /// `vtable_from_existing_allocation` returned before.
+    fn cached_vtable_layout(&self, vtable: Pointer<M::PointerTag>) -> Lrc<VtableLayout> {
+        self.vtable_layouts
+            .get(&vtable.alloc_id)
+            .cloned()
+            .unwrap_or_else(|| bug!("no layout recorded for vtable {:?}", vtable))
+    }
+
+    /// Returns the vtable for unsizing a value of type `source_ty` to `target_ty`, whose unsized
+    /// tail must be a trait object. The vtable is the one for the corresponding tail of
+    /// `source_ty` and the principal trait of that trait object, e.g. `T: Trait` when coercing
+    /// `Foo<T>` to `Foo<dyn Trait>`.
+    pub fn vtable_for_dyn_value(
+        &mut self,
+        source_ty: Ty<'tcx>,
+        target_ty: Ty<'tcx>,
+    ) -> InterpResult<'tcx, Pointer<M::PointerTag>> {
+        let (source_tail, target_tail) =
+            self.tcx.struct_lockstep_tails_erasing_lifetimes(source_ty, target_ty, self.param_env);
+        match *target_tail.kind() {
+            ty::Dynamic(ref data, _) => self.get_vtable(source_tail, data.principal()),
+            _ => span_bug!(
+                self.cur_span(),
+                "cannot get a vtable for unsizing {:?} to {:?}",
+                source_ty,
+                target_ty
+            ),
+        }
     }
 
     /// Resolves the function at the specified slot in the provided
@@ -109,7 +297,7 @@ pub fn get_vtable_slot(
     ) -> InterpResult<'tcx, FnVal<'tcx, M::ExtraFnVal>> {
         let ptr_size = self.pointer_size();
         // Skip over the 'drop_ptr', 'size', and 'align' fields.
-        let vtable_slot = vtable.ptr_offset(ptr_size * idx.checked_add(3).unwrap(), self)?;
+        let vtable_slot = vtable.ptr_offset(self.vtable_method_slot_offset(idx)?, self)?;
         let vtable_slot = self
             .memory
             .check_ptr_access(vtable_slot, ptr_size, self.tcx.data_layout.pointer_align.abi)?
@@ -123,21 +311,30 @@ pub fn get_vtable_slot(
     }
 
     /// Returns the drop fn instance as well as the actual dynamic type.
+    ///
+    /// Vtables are flat: the methods of supertraits are laid out in the vtable of the trait
+    /// object's principal, and there are no separate supertrait vtables. So the drop slot is
+    /// always at `vtable_drop_slot_offset`, also for trait objects of subtraits and for trait
+    /// objects nested in the dynamic type of another trait object.
     pub fn read_drop_type_from_vtable(
         &self,
         vtable: Scalar<M::PointerTag>,
     ) -> InterpResult<'tcx, (ty::Instance<'tcx>, Ty<'tcx>)> {
+        let drop_slot = vtable.ptr_offset(self.vtable_drop_slot_offset(), self)?;
         // We don't care about the pointee type; we just want a pointer.
-        let vtable = self
+        let drop_slot = self
             .memory
             .check_ptr_access(
-                vtable,
+                drop_slot,
                 self.tcx.data_layout.pointer_size,
                 self.tcx.data_layout.pointer_align.abi,
             )?
             .expect("cannot be a ZST");
-        let drop_fn =
-            self.memory.get_raw(vtable.alloc_id)?.read_ptr_sized(self, vtable)?.check_init()?;
+        let drop_fn = self
+            .memory
+            .get_raw(drop_slot.alloc_id)?
+            .read_ptr_sized(self, drop_slot)?
+            .check_init()?;
         // We *need* an instance here, no other kind of function value, to be able
         // to determine the type.
         let drop_instance = self.memory.get_fn(drop_fn)?.as_instance()?;
@@ -158,25 +355,360 @@ pub fn read_size_and_align_from_vtable(
         vtable: Scalar<M::PointerTag>,
     ) -> InterpResult<'tcx, (Size, Align)> {
         let pointer_size = self.pointer_size();
-        // We check for `size = 3 * ptr_size`, which covers the drop fn (unused here),
+        // We check for the size of the header, which covers the drop fn (unused here),
         // the size, and the align (which we read below).
         let vtable = self
             .memory
-            .check_ptr_access(vtable, 3 * pointer_size, self.tcx.data_layout.pointer_align.abi)?
+            .check_ptr_access(
+                vtable,
+                self.vtable_header_size(),
+                self.tcx.data_layout.pointer_align.abi,
+            )?
             .expect("cannot be a ZST");
         let alloc = self.memory.get_raw(vtable.alloc_id)?;
-        let size = alloc.read_ptr_sized(self, vtable.offset(pointer_size, self)?)?.check_init()?;
+        let size_ptr = vtable.offset(self.vtable_size_slot_offset(), self)?;
+        let size = alloc.read_ptr_sized(self, size_ptr)?.check_init()?;
         let size = u64::try_from(self.force_bits(size, pointer_size)?).unwrap();
-        let align =
-            alloc.read_ptr_sized(self, vtable.offset(pointer_size * 2, self)?)?.check_init()?;
+        let align_ptr = vtable.offset(self.vtable_align_slot_offset(), self)?;
+        let align = alloc.read_ptr_sized(self, align_ptr)?.check_init()?;
         let align = u64::try_from(self.force_bits(align, pointer_size)?).unwrap();
 
         if size >= self.tcx.data_layout.obj_size_bound() {
+            throw_ub!(InvalidVtableSize);
+        }
+        let align = Align::from_bytes(align).map_err(|e| err_ub!(InvalidVtableAlignment(e)))?;
+        Ok((Size::from_bytes(size), align))
+    }
+
+    /// Returns the size of the allocation `vtable` points into. For vtables created by
+    /// `get_vtable`, this is `VTABLE_HEADER_WORDS` plus the number of methods, in pointers.
+    pub(crate) fn vtable_allocation_size(
+        &self,
+        vtable: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx, Size> {
+        let vtable = self
+            .memory
+            .check_ptr_access(
+                vtable,
+                self.vtable_header_size(),
+                self.tcx.data_layout.pointer_align.abi,
+            )?
+            .expect("cannot be a ZST");
+        let (size, _) =
+            self.memory.get_size_and_align(vtable.alloc_id, AllocCheck::Dereferenceable)?;
+        Ok(size)
+    }
+
+    /// Checks that `vtable` points to something that looks like a vtable created by
+    /// `get_vtable`: a drop/size/align header followed by zero or more method slots, where
+    /// the size is a multiple of the alignment and every method slot is either empty or
+    /// a valid function pointer.
+    pub(crate) fn assert_vtable_well_formed(
+        &self,
+        vtable: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx> {
+        let vtable = self
+            .memory
+            .check_ptr_access(
+                vtable,
+                self.vtable_header_size(),
+                self.tcx.data_layout.pointer_align.abi,
+            )?
+            .expect("cannot be a ZST");
+        if vtable.offset.bytes() != 0 {
+            throw_ub!(InvalidVtableLayout(format!(
+                "pointer at offset {} is not at the start of its allocation",
+                vtable.offset.bytes()
+            )));
+        }
+        self.vtable_ptr_size_mismatch_check(vtable.into())?;
+
+        self.read_drop_type_from_vtable(vtable.into())?;
+        let (size, align) = self.read_size_and_align_from_vtable(vtable.into())?;
+        if size.bytes() % align.bytes() != 0 {
+            throw_ub!(InvalidVtableLayout(format!(
+                "size {} is not a multiple of alignment {}",
+                size.bytes(),
+                align.bytes()
+            )));
+        }
+
+        self.read_vtable_all_methods(vtable.into())?;
+        Ok(())
+    }
+
+    /// Checks that `data` and `vtable` can be combined into a trait object, e.g. one built with
+    /// a transmute: `vtable` must be well-formed, and `data` must be aligned and dereferenceable
+    /// for the size and alignment the vtable records.
+    pub fn validate_fat_pointer(
+        &self,
+        data: Scalar<M::PointerTag>,
+        vtable: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx> {
+        self.assert_vtable_well_formed(vtable)?;
+        let (size, align) = self.read_size_and_align_from_vtable(vtable)?;
+        self.memory.check_ptr_access(data, size, align)?;
+        Ok(())
+    }
+
+    /// Checks that the allocation `vtable` points into is laid out for the pointer size of the
+    /// target, which all vtable accesses use (and not the host's): its size must be a whole
+    /// number of pointers and it must be aligned for pointers.
+    pub fn vtable_ptr_size_mismatch_check(
+        &self,
+        vtable: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx> {
+        let ptr_size = self.pointer_size();
+        let ptr_align = self.tcx.data_layout.pointer_align.abi;
+        let vtable = self
+            .memory
+            .check_ptr_access(vtable, self.vtable_header_size(), ptr_align)?
+            .expect("cannot be a ZST");
+        let (size, align) =
+            self.memory.get_size_and_align(vtable.alloc_id, AllocCheck::Dereferenceable)?;
+        if size.bytes() % ptr_size.bytes() != 0 {
+            throw_ub!(InvalidVtableLayout(format!(
+                "allocation size {} is not a multiple of the pointer size {}",
+                size.bytes(),
+                ptr_size.bytes()
+            )));
+        }
+        if align < ptr_align {
+            throw_ub!(InvalidVtableLayout(format!(
+                "allocation alignment {} is less than the pointer alignment {}",
+                align.bytes(),
+                ptr_align.bytes()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reads all method slots of the given vtable. Slots of methods that cannot be called
+    /// on the trait object (e.g. because they have a `Self: Sized` bound) are `None`.
+    pub(crate) fn read_vtable_all_methods(
+        &self,
+        vtable: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx, Vec<Option<Instance<'tcx>>>> {
+        let methods = self.vtable_method_count(vtable)?;
+        (0..methods).map(|i| self.read_vtable_entry(vtable, i)).collect()
+    }
+
+    /// Returns the number of method slots of the given vtable, i.e. the number of pointers
+    /// between the end of its header and the end of its allocation.
+    fn vtable_method_count(&self, vtable: Scalar<M::PointerTag>) -> InterpResult<'tcx, u64> {
+        let vtable = self
+            .memory
+            .check_ptr_access(
+                vtable,
+                self.vtable_header_size(),
+                self.tcx.data_layout.pointer_align.abi,
+            )?
+            .expect("cannot be a ZST");
+        let alloc_size = self.vtable_allocation_size(vtable.into())?;
+        Ok((alloc_size - vtable.offset).bytes() / self.pointer_size().bytes() - VTABLE_HEADER_WORDS)
+    }
+
+    /// Reads the method slot with the given index of the given vtable, where index 0 is the
+    /// first method declared in the trait. Returns `None` if the slot is empty, and an error if
+    /// the vtable has no slot with that index.
+    pub fn read_vtable_entry(
+        &self,
+        vtable: Scalar<M::PointerTag>,
+        method_index: u64,
+    ) -> InterpResult<'tcx, Option<Instance<'tcx>>> {
+        let methods = self.vtable_method_count(vtable)?;
+        if method_index >= methods {
             throw_ub_format!(
-                "invalid vtable: \
-                size is bigger than largest supported object"
+                "vtable method index {} is out of range for a vtable with {} methods",
+                method_index,
+                methods
             );
         }
-        Ok((Size::from_bytes(size), Align::from_bytes(align).unwrap()))
+        let slot = vtable.ptr_offset(self.vtable_method_slot_offset(method_index)?, self)?;
+        let slot = self
+            .memory
+            .check_ptr_access(slot, self.pointer_size(), self.tcx.data_layout.pointer_align.abi)?
+            .expect("cannot be a ZST");
+        self.read_vtable_fn_slot(slot)
+    }
+
+    /// Like `read_vtable_entry`, but returns the `DefId` and substitutions of the method in the
+    /// slot, so that callers can inspect its generic arguments (including const arguments).
+    pub fn vtable_method_instance_at(
+        &self,
+        vtable: Scalar<M::PointerTag>,
+        method_index: u64,
+    ) -> InterpResult<'tcx, Option<(DefId, SubstsRef<'tcx>)>> {
+        let instance = self.read_vtable_entry(vtable, method_index)?;
+        Ok(instance.map(|instance| (instance.def_id(), instance.substs)))
+    }
+
+    /// Reads the function pointer in the vtable slot that `slot` points to. Returns `None`
+    /// if the slot is empty, i.e. uninitialized or null.
+    fn read_vtable_fn_slot(
+        &self,
+        slot: Pointer<M::PointerTag>,
+    ) -> InterpResult<'tcx, Option<Instance<'tcx>>> {
+        let ptr_size = self.pointer_size();
+        match self.memory.get_raw(slot.alloc_id)?.read_ptr_sized(self, slot)? {
+            ScalarMaybeUninit::Scalar(fn_ptr)
+                if !matches!(fn_ptr.to_bits_or_ptr(ptr_size, self), Ok(0)) =>
+            {
+                Ok(Some(self.memory.get_fn(fn_ptr)?.as_instance()?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Reads the entire contents of a vtable into a `VtableDump`.
+    pub(crate) fn dump_vtable(
+        &self,
+        vtable: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx, VtableDump<'tcx>> {
+        let vtable_ptr = self
+            .memory
+            .check_ptr_access(
+                vtable,
+                self.vtable_header_size(),
+                self.tcx.data_layout.pointer_align.abi,
+            )?
+            .expect("cannot be a ZST");
+        let drop = self.read_vtable_fn_slot(vtable_ptr)?;
+        let (size, align) = self.read_size_and_align_from_vtable(vtable)?;
+        let methods = self.read_vtable_all_methods(vtable)?;
+        Ok(VtableDump { drop, size: size.bytes(), align: align.bytes(), methods })
+    }
+
+    /// Compares two vtables slot by slot and describes every slot in which they differ.
+    /// Slots are numbered like the words of the vtable, so the drop function, size and
+    /// align are slots 0, 1 and 2, and the methods start at `VTABLE_HEADER_WORDS`.
+    pub(crate) fn vtable_diff(
+        &self,
+        a: Scalar<M::PointerTag>,
+        b: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx, Vec<(u64, String)>> {
+        let mut diff = vec![];
+
+        let (drop_a, _) = self.read_drop_type_from_vtable(a)?;
+        let (drop_b, _) = self.read_drop_type_from_vtable(b)?;
+        if drop_a != drop_b {
+            diff.push((0, format!("drop: `{}` != `{}`", drop_a, drop_b)));
+        }
+
+        let (size_a, align_a) = self.read_size_and_align_from_vtable(a)?;
+        let (size_b, align_b) = self.read_size_and_align_from_vtable(b)?;
+        if size_a != size_b {
+            diff.push((1, format!("size: {} != {}", size_a.bytes(), size_b.bytes())));
+        }
+        if align_a != align_b {
+            diff.push((2, format!("align: {} != {}", align_a.bytes(), align_b.bytes())));
+        }
+
+        let methods_a = self.read_vtable_all_methods(a)?;
+        let methods_b = self.read_vtable_all_methods(b)?;
+        let describe = |method: Option<&Option<Instance<'tcx>>>| match method {
+            Some(Some(instance)) => format!("`{}`", instance),
+            Some(None) => "empty".to_string(),
+            None => "missing".to_string(),
+        };
+        for i in 0..methods_a.len().max(methods_b.len()) {
+            let (method_a, method_b) = (methods_a.get(i), methods_b.get(i));
+            if method_a != method_b {
+                diff.push((
+                    VTABLE_HEADER_WORDS + i as u64,
+                    format!("method {}: {} != {}", i, describe(method_a), describe(method_b)),
+                ));
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Returns whether two vtables have the same contents, i.e. the same drop function, size,
+    /// alignment and methods. Unlike comparing the pointers, this also treats vtables as equal
+    /// that were not deduplicated, e.g. ones created with the vtable cache disabled.
+    pub fn vtable_equal(
+        &self,
+        a: Scalar<M::PointerTag>,
+        b: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx, bool> {
+        Ok(self.vtable_contents_key(a)? == self.vtable_contents_key(b)?)
+    }
+
+    /// Reads the drop function, size, alignment and methods of a vtable, which is the key that
+    /// `get_vtable` deduplicates vtables with identical contents on.
+    fn vtable_contents_key(
+        &self,
+        vtable: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx, (Instance<'tcx>, Size, Align, Vec<Option<Instance<'tcx>>>)> {
+        let (drop, _) = self.read_drop_type_from_vtable(vtable)?;
+        let (size, align) = self.read_size_and_align_from_vtable(vtable)?;
+        let methods = self.read_vtable_all_methods(vtable)?;
+        Ok((drop, size, align, methods))
+    }
+
+    /// Turns an allocation that was filled with vtable contents by someone else (e.g. an
+    /// embedder of the interpreter) into a vtable. `vtable` must point to the start of the
+    /// allocation, which is checked to be a well-formed vtable, made immutable, and registered
+    /// so that `get_vtable` reuses it for any type and trait with the same contents.
+    ///
+    /// If a vtable with the same contents already exists, that one is returned instead.
+    pub fn vtable_from_existing_allocation(
+        &mut self,
+        vtable: Pointer<M::PointerTag>,
+    ) -> InterpResult<'tcx, Pointer<M::PointerTag>> {
+        self.assert_vtable_well_formed(vtable.into())?;
+        let contents = self.vtable_contents_key(vtable.into())?;
+
+        if self.memory.get_raw(vtable.alloc_id)?.mutability == Mutability::Mut {
+            self.memory.mark_immutable(vtable.alloc_id)?;
+        }
+        let layout = VtableLayout::new(&contents.3);
+        let vtable = *self.vtable_contents.entry(contents).or_insert(vtable);
+        self.vtable_layouts.entry(vtable.alloc_id).or_insert_with(|| Lrc::new(layout));
+        Ok(vtable)
+    }
+
+    /// Finds the method called `name` in the vtable for `trait_ref`, returning its method index
+    /// (which is what `get_vtable_slot` takes) and the instance stored in its slot. Returns
+    /// `None` if there is no such method or if it has no slot in the vtable (e.g. because it
+    /// requires `Self: Sized`).
+    pub(crate) fn resolve_vtable_method_by_name(
+        &self,
+        trait_ref: ty::PolyTraitRef<'tcx>,
+        name: Symbol,
+    ) -> InterpResult<'tcx, Option<(u64, Instance<'tcx>)>> {
+        let tcx = *self.tcx;
+        let trait_ref = tcx.erase_regions(trait_ref);
+        let methods = tcx.vtable_methods(trait_ref);
+        let found = methods.iter().enumerate().find_map(|(i, method)| match *method {
+            Some((def_id, substs)) if tcx.item_name(def_id) == name => Some((i, def_id, substs)),
+            _ => None,
+        });
+        let (i, def_id, substs) = match found {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        let instance = ty::Instance::resolve_for_vtable(tcx, self.param_env, def_id, substs)
+            .ok_or_else(|| err_inval!(TooGeneric))?;
+        Ok(Some((u64::try_from(i).unwrap(), instance)))
+    }
+
+    /// If `vtable` was created by `get_vtable` for one of the `Fn`, `FnMut` or `FnOnce`
+    /// traits, returns which one. Returns `None` for vtables of other traits, vtables without
+    /// a principal trait, and pointers that are not vtables created by this interpreter.
+    pub(crate) fn vtable_is_for_fn_trait(
+        &self,
+        vtable: Pointer<M::PointerTag>,
+    ) -> Option<ty::ClosureKind> {
+        let cached = self
+            .vtables
+            .iter()
+            .filter(|&(_, &candidate)| candidate == vtable)
+            .map(|(&(_, poly_trait_ref), _)| poly_trait_ref);
+        let uncached = self.uncached_vtable_principals.get(&vtable.alloc_id).copied();
+        cached.chain(uncached).find_map(|poly_trait_ref| {
+            self.tcx.fn_trait_kind_from_lang_item(poly_trait_ref?.def_id())
+        })
     }
 }
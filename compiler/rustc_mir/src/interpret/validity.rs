@@ -308,10 +308,13 @@ fn with_elem<R>(
         Ok(r)
     }
 
+    /// Checks the metadata of a wide pointer. `data` is the data pointer for wide pointers that
+    /// must be dereferenceable (references and boxes), and `None` for raw pointers.
     fn check_wide_ptr_meta(
         &mut self,
         meta: MemPlaceMeta<M::PointerTag>,
         pointee: TyAndLayout<'tcx>,
+        data: Option<super::Scalar<M::PointerTag>>,
     ) -> InterpResult<'tcx> {
         let tail = self.ecx.tcx.struct_tail_erasing_lifetimes(pointee.ty, self.ecx.param_env);
         match tail.kind() {
@@ -321,7 +324,7 @@ fn check_wide_ptr_meta(
                 try_validation!(
                     self.ecx.memory.check_ptr_access_align(
                         vtable,
-                        3 * self.ecx.tcx.data_layout.pointer_size, // drop, size, align
+                        self.ecx.vtable_header_size(), // drop, size, align
                         Some(self.ecx.tcx.data_layout.pointer_align.abi),
                         CheckInAllocMsg::InboundsTest,
                     ),
@@ -349,7 +352,35 @@ fn check_wide_ptr_meta(
                     self.ecx.read_size_and_align_from_vtable(vtable),
                     self.path,
                     err_unsup!(ReadPointerAsBytes) => { "invalid size or align in vtable" },
+                    err_ub!(InvalidVtableSize) =>
+                        { "invalid vtable: size is bigger than largest supported object" },
+                    err_ub!(InvalidVtableAlignment(msg)) =>
+                        { "invalid vtable: alignment {}", msg },
                 );
+                if self.ecx.tcx.sess.opts.debugging_opts.validate_vtables {
+                    try_validation!(
+                        self.ecx.assert_vtable_well_formed(vtable),
+                        self.path,
+                        err_ub!(InvalidVtableLayout(msg)) => { "invalid vtable: {}", msg },
+                        err_ub!(DanglingIntPointer(..)) |
+                        err_ub!(InvalidFunctionPointer(..)) |
+                        err_unsup!(ReadBytesAsPointer) =>
+                            { "invalid method pointer in vtable (not pointing to a function)" },
+                    );
+                    if let Some(data) = data {
+                        // The vtable is well-formed, so any error here is about the data.
+                        try_validation!(
+                            self.ecx.validate_fat_pointer(data, vtable),
+                            self.path,
+                            err_ub!(DanglingIntPointer(..)) |
+                            err_ub!(PointerOutOfBounds { .. }) |
+                            err_unsup!(ReadBytesAsPointer) =>
+                                { "wide pointer to data smaller than the size in its vtable" },
+                            err_ub!(AlignmentCheckFailed { .. }) =>
+                                { "wide pointer to data less aligned than the alignment in its vtable" },
+                        );
+                    }
+                }
                 // FIXME: More checks for the vtable.
             }
             ty::Slice(..) | ty::Str => {
@@ -386,7 +417,7 @@ fn check_safe_pointer(
             err_ub!(InvalidUninitBytes(None)) => { "uninitialized {}", kind },
         );
         if place.layout.is_unsized() {
-            self.check_wide_ptr_meta(place.meta, place.layout)?;
+            self.check_wide_ptr_meta(place.meta, place.layout, Some(place.ptr))?;
         }
         // Make sure this is dereferenceable and all.
         let size_and_align = try_validation!(
@@ -538,7 +569,7 @@ fn try_visit_primitive(
                     err_ub!(InvalidUninitBytes(None)) => { "uninitialized raw pointer" },
                 );
                 if place.layout.is_unsized() {
-                    self.check_wide_ptr_meta(place.meta, place.layout)?;
+                    self.check_wide_ptr_meta(place.meta, place.layout, None)?;
                 }
                 Ok(true)
             }
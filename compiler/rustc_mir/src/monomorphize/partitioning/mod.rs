@@ -102,7 +102,8 @@
 use rustc_middle::mir::mono::{CodegenUnit, Linkage};
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::query::Providers;
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::{self, TyCtxt};
 use rustc_span::symbol::Symbol;
 
 use crate::monomorphize::collector::InliningMap;
@@ -413,9 +414,52 @@ fn collect_and_partition_mono_items<'tcx>(
         }
     }
 
+    if tcx.sess.opts.debugging_opts.dump_const_arg_values {
+        dump_const_arg_values(tcx, items.iter());
+    }
+
     (tcx.arena.alloc(mono_items), codegen_units)
 }
 
+/// Prints the evaluated value of every const argument of the monomorphized functions in
+/// `mono_items`, together with the item and the parameter it was supplied to. The lines are
+/// sorted, so that the output doesn't depend on the order in which items were collected.
+fn dump_const_arg_values<'a, 'tcx: 'a>(
+    tcx: TyCtxt<'tcx>,
+    mono_items: impl Iterator<Item = &'a MonoItem<'tcx>>,
+) {
+    let mut lines = Vec::new();
+    for mono_item in mono_items {
+        let instance = match *mono_item {
+            MonoItem::Fn(instance) => instance,
+            MonoItem::Static(_) | MonoItem::GlobalAsm(_) => continue,
+        };
+        let generics = tcx.generics_of(instance.def_id());
+        for (index, arg) in instance.substs.iter().enumerate() {
+            let ct = match arg.unpack() {
+                GenericArgKind::Const(ct) => ct,
+                GenericArgKind::Lifetime(_) | GenericArgKind::Type(_) => continue,
+            };
+            // Shims can have more arguments than the item they are generated for has parameters.
+            if index >= generics.count() {
+                continue;
+            }
+            let param = generics.param_at(index, tcx);
+            let ct = ct.eval(tcx, ty::ParamEnv::reveal_all());
+            lines.push(with_no_trimmed_paths(|| {
+                format!("CONST_ARG {} {} = {}", tcx.def_path_str(instance.def_id()), param.name, ct)
+            }));
+        }
+    }
+
+    lines.sort();
+    lines.dedup();
+
+    for line in lines {
+        println!("{}", line);
+    }
+}
+
 pub fn provide(providers: &mut Providers) {
     providers.collect_and_partition_mono_items = collect_and_partition_mono_items;
 
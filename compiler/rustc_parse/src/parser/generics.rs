@@ -58,12 +58,24 @@ fn parse_const_param(&mut self, preceding_attrs: Vec<Attribute>) -> PResult<'a,
 
         self.sess.gated_spans.gate(sym::min_const_generics, const_span.to(self.prev_token.span));
 
+        // Parse optional const generics default value.
+        let default = if self.eat(&token::Eq) {
+            let eq_span = self.prev_token.span;
+            let default = self.parse_const_arg()?;
+            self.sess
+                .gated_spans
+                .gate(sym::const_generics_defaults, eq_span.to(default.value.span));
+            Some(default)
+        } else {
+            None
+        };
+
         Ok(GenericParam {
             ident,
             id: ast::DUMMY_NODE_ID,
             attrs: preceding_attrs.into(),
             bounds: Vec::new(),
-            kind: GenericParamKind::Const { ty, kw_span: const_span },
+            kind: GenericParamKind::Const { ty, kw_span: const_span, default },
             is_placeholder: false,
         })
     }
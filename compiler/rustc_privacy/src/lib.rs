@@ -878,8 +878,11 @@ fn generics(&mut self) -> &mut Self {
                         self.visit(self.ev.tcx.type_of(param.def_id));
                     }
                 }
-                GenericParamDefKind::Const => {
+                GenericParamDefKind::Const { has_default } => {
                     self.visit(self.ev.tcx.type_of(param.def_id));
+                    if has_default {
+                        self.visit(self.ev.tcx.const_param_default(param.def_id));
+                    }
                 }
             }
         }
@@ -1691,8 +1694,11 @@ fn generics(&mut self) -> &mut Self {
                         self.visit(self.tcx.type_of(param.def_id));
                     }
                 }
-                GenericParamDefKind::Const => {
+                GenericParamDefKind::Const { has_default } => {
                     self.visit(self.tcx.type_of(param.def_id));
+                    if has_default {
+                        self.visit(self.tcx.const_param_default(param.def_id));
+                    }
                 }
             }
         }
@@ -442,6 +442,19 @@ impl<'a> Resolver<'a> {
                 );
                 err
             }
+            ResolutionError::ForwardDeclaredConstParam => {
+                let mut err = struct_span_err!(
+                    self.session,
+                    span,
+                    E0128,
+                    "const parameters with a default cannot use forward declared identifiers"
+                );
+                err.span_label(
+                    span,
+                    "defaulted const parameters cannot be forward declared".to_string(),
+                );
+                err
+            }
             ResolutionError::ParamInTyOfConstParam(name) => {
                 let mut err = struct_span_err!(
                     self.session,
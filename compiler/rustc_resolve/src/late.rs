@@ -132,6 +132,12 @@ enum PatBoundCtx {
     /// before said type parameter. Also see the `visit_generics` override.
     ForwardTyParamBanRibKind,
 
+    /// All bindings in this rib are generic parameters that can't be used
+    /// from the default of a const parameter because they're not declared
+    /// before said const parameter. Unlike `ForwardTyParamBanRibKind`, this
+    /// does not prevent the default from using the parameters declared earlier.
+    ForwardConstParamBanRibKind,
+
     /// We are inside of the type of a const parameter. Can't refer to any
     /// parameters.
     ConstParamTyRibKind,
@@ -149,7 +155,10 @@ impl RibKind<'_> {
             | ModuleRibKind(_)
             | MacroDefinition(_)
             | ConstParamTyRibKind => false,
-            AssocItemRibKind | ItemRibKind(_) | ForwardTyParamBanRibKind => true,
+            AssocItemRibKind
+            | ItemRibKind(_)
+            | ForwardTyParamBanRibKind
+            | ForwardConstParamBanRibKind => true,
         }
     }
 }
@@ -561,6 +570,28 @@ fn visit_generics(&mut self, generics: &'ast Generics) {
             default_ban_rib.bindings.insert(Ident::with_dummy_span(kw::SelfUpper), Res::Err);
         }
 
+        // Const parameter defaults are anonymous constants which may refer to the type and
+        // const parameters declared before them, but not to those declared after them.
+        let mut const_default_ty_ban_rib = Rib::new(ForwardConstParamBanRibKind);
+        let mut const_default_const_ban_rib = Rib::new(ForwardConstParamBanRibKind);
+        for param in &generics.params {
+            let ident = Ident::with_dummy_span(param.ident.name);
+            match param.kind {
+                GenericParamKind::Lifetime => {}
+                GenericParamKind::Type { .. } => {
+                    const_default_ty_ban_rib.bindings.insert(ident, Res::Err);
+                }
+                GenericParamKind::Const { .. } => {
+                    const_default_const_ban_rib.bindings.insert(ident, Res::Err);
+                }
+            }
+        }
+        if self.diagnostic_metadata.current_self_item.is_some() {
+            const_default_ty_ban_rib
+                .bindings
+                .insert(Ident::with_dummy_span(kw::SelfUpper), Res::Err);
+        }
+
         for param in &generics.params {
             match param.kind {
                 GenericParamKind::Lifetime => self.visit_generic_param(param),
@@ -585,8 +616,11 @@ fn visit_generics(&mut self, generics: &'ast Generics) {
 
                     // Allow all following defaults to refer to this type parameter.
                     default_ban_rib.bindings.remove(&Ident::with_dummy_span(param.ident.name));
+                    const_default_ty_ban_rib
+                        .bindings
+                        .remove(&Ident::with_dummy_span(param.ident.name));
                 }
-                GenericParamKind::Const { ref ty, kw_span: _ } => {
+                GenericParamKind::Const { ref ty, kw_span: _, ref default } => {
                     for bound in &param.bounds {
                         self.visit_param_bound(bound);
                     }
@@ -595,6 +629,19 @@ fn visit_generics(&mut self, generics: &'ast Generics) {
                     self.visit_ty(ty);
                     self.ribs[TypeNS].pop().unwrap();
                     self.ribs[ValueNS].pop().unwrap();
+
+                    if let Some(ref default) = default {
+                        self.ribs[TypeNS].push(const_default_ty_ban_rib);
+                        self.ribs[ValueNS].push(const_default_const_ban_rib);
+                        self.visit_anon_const(default);
+                        const_default_const_ban_rib = self.ribs[ValueNS].pop().unwrap();
+                        const_default_ty_ban_rib = self.ribs[TypeNS].pop().unwrap();
+                    }
+
+                    // Allow all following defaults to refer to this const parameter.
+                    const_default_const_ban_rib
+                        .bindings
+                        .remove(&Ident::with_dummy_span(param.ident.name));
                 }
             }
         }
@@ -845,6 +892,7 @@ fn is_label_valid_from_rib(&self, rib_index: usize) -> bool {
                 | ConstantItemRibKind(_)
                 | ModuleRibKind(..)
                 | ForwardTyParamBanRibKind
+                | ForwardConstParamBanRibKind
                 | ConstParamTyRibKind => {
                     return false;
                 }
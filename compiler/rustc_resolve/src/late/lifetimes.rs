@@ -867,12 +867,15 @@ fn visit_generics(&mut self, generics: &'tcx hir::Generics<'tcx>) {
                         self.visit_ty(&ty);
                     }
                 }
-                GenericParamKind::Const { ref ty, .. } => {
+                GenericParamKind::Const { ref ty, ref default } => {
                     let was_in_const_generic = self.is_in_const_generic;
                     self.is_in_const_generic = true;
                     walk_list!(self, visit_param_bound, param.bounds);
                     self.visit_ty(&ty);
                     self.is_in_const_generic = was_in_const_generic;
+                    if let Some(ref default) = default {
+                        self.visit_anon_const(default);
+                    }
                 }
             }
         }
@@ -1966,7 +1969,7 @@ fn visit_segment_args(
                             GenericParamDefKind::Type { object_lifetime_default, .. } => {
                                 Some(object_lifetime_default)
                             }
-                            GenericParamDefKind::Lifetime | GenericParamDefKind::Const => None,
+                            GenericParamDefKind::Lifetime | GenericParamDefKind::Const { .. } => None,
                         })
                         .collect()
                 })
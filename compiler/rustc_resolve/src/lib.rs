@@ -214,7 +214,9 @@ enum ResolutionError<'a> {
     /// Error E0530: `X` bindings cannot shadow `Y`s.
     BindingShadowsSomethingUnacceptable(&'static str, Symbol, &'a NameBinding<'a>),
     /// Error E0128: type parameters with a default cannot use forward-declared identifiers.
-    ForwardDeclaredTyParam, // FIXME(const_generics:defaults)
+    ForwardDeclaredTyParam,
+    /// Error E0128: const parameters with a default cannot use forward-declared identifiers.
+    ForwardDeclaredConstParam,
     /// ERROR E0770: the type of const parameters must not depend on other generic parameters.
     ParamInTyOfConstParam(Symbol),
     /// constant values inside of type parameter defaults must not depend on generic parameters.
@@ -2556,6 +2558,15 @@ fn validate_res_from_ribs(
             return Res::Err;
         }
 
+        // An invalid forward use of a generic parameter from the default of a const parameter.
+        if let ForwardConstParamBanRibKind = all_ribs[rib_index].kind {
+            if record_used {
+                self.report_error(span, ResolutionError::ForwardDeclaredConstParam);
+            }
+            assert_eq!(res, Res::Err);
+            return Res::Err;
+        }
+
         match res {
             Res::Local(_) => {
                 use ResolutionError::*;
@@ -2567,7 +2578,8 @@ fn validate_res_from_ribs(
                         | ClosureOrAsyncRibKind
                         | ModuleRibKind(..)
                         | MacroDefinition(..)
-                        | ForwardTyParamBanRibKind => {
+                        | ForwardTyParamBanRibKind
+                        | ForwardConstParamBanRibKind => {
                             // Nothing to do. Continue.
                         }
                         ItemRibKind(_) | FnItemRibKind | AssocItemRibKind => {
@@ -2604,6 +2616,7 @@ fn validate_res_from_ribs(
             }
             Res::Def(DefKind::TyParam, _) | Res::SelfTy(..) => {
                 let mut in_ty_param_default = false;
+                let mut in_const_param_default = false;
                 for rib in ribs {
                     let has_generic_params = match rib.kind {
                         NormalRibKind
@@ -2621,7 +2634,26 @@ fn validate_res_from_ribs(
                             in_ty_param_default = true;
                             continue;
                         }
+                        // Likewise, the defaults of const parameters may not depend on type
+                        // parameters, e.g. `struct Foo<T, const N: usize = { size_of::<T>() }>`.
+                        ForwardConstParamBanRibKind => {
+                            in_const_param_default = true;
+                            continue;
+                        }
                         ConstantItemRibKind(trivial) => {
+                            if in_const_param_default {
+                                if record_used {
+                                    self.report_error(
+                                        span,
+                                        ResolutionError::ParamInNonTrivialAnonConst {
+                                            name: rib_ident.name,
+                                            is_type: true,
+                                        },
+                                    );
+                                }
+                                return Res::Err;
+                            }
+
                             // HACK(min_const_generics): We currently only allow `N` or `{ N }`.
                             if !trivial && self.session.features_untracked().min_const_generics {
                                 // HACK(min_const_generics): If we encounter `Self` in an anonymous constant
@@ -2694,6 +2726,7 @@ fn validate_res_from_ribs(
                 }
 
                 let mut in_ty_param_default = false;
+                let mut in_const_param_default = false;
                 for rib in ribs {
                     let has_generic_params = match rib.kind {
                         NormalRibKind
@@ -2708,9 +2741,16 @@ fn validate_res_from_ribs(
                             in_ty_param_default = true;
                             continue;
                         }
+                        // The defaults of const parameters may only use earlier const parameters
+                        // on their own, e.g. `struct Foo<const N: usize, const M: usize = N>`.
+                        ForwardConstParamBanRibKind => {
+                            in_const_param_default = true;
+                            continue;
+                        }
                         ConstantItemRibKind(trivial) => {
+                            let features = self.session.features_untracked();
                             // HACK(min_const_generics): We currently only allow `N` or `{ N }`.
-                            if !trivial && self.session.features_untracked().min_const_generics {
+                            if !trivial && (features.min_const_generics || in_const_param_default) {
                                 if record_used {
                                     self.report_error(
                                         span,
@@ -270,6 +270,7 @@ mod $mod_desc {
         pub const parse_merge_functions: &str = "one of: `disabled`, `trampolines`, or `aliases`";
         pub const parse_symbol_mangling_version: &str = "either `legacy` or `v0` (RFC 2603)";
         pub const parse_src_file_hash: &str = "either `md5` or `sha1`";
+        pub const parse_const_generic_overflow: &str = "either `error` or `wrap`";
         pub const parse_relocation_model: &str =
             "one of supported relocation models (`rustc --print relocation-models`)";
         pub const parse_code_model: &str =
@@ -687,6 +688,15 @@ fn parse_symbol_mangling_version(
             true
         }
 
+        fn parse_const_generic_overflow(slot: &mut ConstGenericOverflow, v: Option<&str>) -> bool {
+            *slot = match v {
+                Some("error") => ConstGenericOverflow::Error,
+                Some("wrap") => ConstGenericOverflow::Wrap,
+                _ => return false,
+            };
+            true
+        }
+
         fn parse_src_file_hash(slot: &mut Option<SourceFileHashAlgorithm>, v: Option<&str>) -> bool {
             match v.and_then(|s| SourceFileHashAlgorithm::from_str(s).ok()) {
                 Some(hash_kind) => *slot = Some(hash_kind),
@@ -852,6 +862,13 @@ fn parse_target_feature(slot: &mut String, v: Option<&str>) -> bool {
         "the backend to use"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
         "combine CGUs into a single one"),
+    const_eval_max_vtables: usize = (10_000, parse_uint, [TRACKED],
+        "the maximum number of distinct vtables a single constant evaluation may create \
+        (default: 10000)"),
+    const_generic_overflow: ConstGenericOverflow = (ConstGenericOverflow::Error,
+        parse_const_generic_overflow, [TRACKED],
+        "what to do when arithmetic overflows while evaluating a const argument or array \
+        length: `error` (default) or `wrap` with a warning"),
     crate_attr: Vec<String> = (Vec::new(), parse_string_push, [TRACKED],
         "inject the given attribute in the crate"),
     debug_macros: bool = (false, parse_bool, [TRACKED],
@@ -869,6 +886,9 @@ fn parse_target_feature(slot: &mut String, v: Option<&str>) -> bool {
         (default: no)"),
     dual_proc_macros: bool = (false, parse_bool, [TRACKED],
         "load proc macros for both target and host, but only link to the target (default: no)"),
+    dump_const_arg_values: bool = (false, parse_bool, [UNTRACKED],
+        "print the evaluated value of every const argument of a monomorphized function \
+        (default: no)"),
     dump_dep_graph: bool = (false, parse_bool, [UNTRACKED],
         "dump the dependency graph to $RUST_DEP_GRAPH (default: /tmp/dep_graph.gv) \
         (default: no)"),
@@ -1084,6 +1104,8 @@ fn parse_target_feature(slot: &mut String, v: Option<&str>) -> bool {
         "exclude spans when debug-printing compiler state (default: no)"),
     src_hash_algorithm: Option<SourceFileHashAlgorithm> = (None, parse_src_file_hash, [TRACKED],
         "hash algorithm of source files in debug info (`md5`, `sha1`, or `sha256`)"),
+    strict_unused_lifetimes: bool = (false, parse_bool, [TRACKED],
+        "also report unused lifetime parameters of traits as errors (default: no)"),
     strip: Strip = (Strip::None, parse_strip, [UNTRACKED],
         "tell the linker which information to strip (`none` (default), `debuginfo` or `symbols`)"),
     symbol_mangling_version: SymbolManglingVersion = (SymbolManglingVersion::Legacy,
@@ -1139,6 +1161,8 @@ fn parse_target_feature(slot: &mut String, v: Option<&str>) -> bool {
         "use legacy .ctors section for initializers rather than .init_array"),
     validate_mir: bool = (false, parse_bool, [UNTRACKED],
         "validate MIR after each transformation"),
+    validate_vtables: bool = (false, parse_bool, [TRACKED],
+        "check that vtables are well-formed when validating const-eval results (default: no)"),
     verbose: bool = (false, parse_bool, [UNTRACKED],
         "in general, enable more debug printouts (default: no)"),
     verify_llvm_ir: bool = (false, parse_bool, [TRACKED],
@@ -1142,13 +1142,18 @@ fn report_fulfillment_error(
                 .emit();
             }
             FulfillmentErrorCode::CodeConstEquateError(ref expected_found, ref err) => {
-                self.report_mismatched_consts(
+                let mut diag = self.report_mismatched_consts(
                     &error.obligation.cause,
                     expected_found.expected,
                     expected_found.found,
                     err.clone(),
-                )
-                .emit();
+                );
+                // When the constants come from a where clause such as `Assert<{ N > 0 }>: IsTrue`,
+                // point at the bound that doesn't hold for the given arguments.
+                if let ObligationCauseCode::BindingObligation(..) = error.obligation.cause.code {
+                    self.note_obligation_cause(&mut diag, &error.obligation);
+                }
+                diag.emit();
             }
         }
     }
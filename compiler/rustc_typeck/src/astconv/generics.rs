@@ -183,7 +183,7 @@ pub fn create_substs_for_generic_args<'a>(
                         match (arg, &param.kind, arg_count.explicit_late_bound) {
                             (GenericArg::Lifetime(_), GenericParamDefKind::Lifetime, _)
                             | (GenericArg::Type(_), GenericParamDefKind::Type { .. }, _)
-                            | (GenericArg::Const(_), GenericParamDefKind::Const, _) => {
+                            | (GenericArg::Const(_), GenericParamDefKind::Const { .. }, _) => {
                                 substs.push(ctx.provided_kind(param, arg));
                                 args.next();
                                 params.next();
@@ -229,7 +229,7 @@ pub fn create_substs_for_generic_args<'a>(
                                                     GenericParamDefKind::Type { .. } => {
                                                         ParamKindOrd::Type
                                                     }
-                                                    GenericParamDefKind::Const => {
+                                                    GenericParamDefKind::Const { .. } => {
                                                         ParamKindOrd::Const {
                                                             unordered: tcx
                                                                 .features()
@@ -379,8 +379,8 @@ pub(crate) fn check_generic_arg_count(
                 GenericParamDefKind::Type { has_default, .. } => {
                     defaults.types += has_default as usize
                 }
-                GenericParamDefKind::Const => {
-                    // FIXME(const_generics:defaults)
+                GenericParamDefKind::Const { has_default } => {
+                    defaults.consts += has_default as usize
                 }
             };
         }
@@ -490,7 +490,7 @@ pub(crate) fn check_generic_arg_count(
             if infer_args {
                 0
             } else {
-                param_counts.consts + named_type_param_count - defaults.types
+                param_counts.consts + named_type_param_count - defaults.types - defaults.consts
             },
             param_counts.consts + named_type_param_count,
             arg_counts.consts + arg_counts.types,
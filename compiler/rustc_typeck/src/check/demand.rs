@@ -6,6 +6,7 @@
 use rustc_ast::util::parser::PREC_POSTFIX;
 use rustc_errors::{Applicability, DiagnosticBuilder};
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{is_range_literal, Node};
 use rustc_middle::ty::adjustment::AllowTwoPhase;
@@ -151,6 +152,32 @@ pub fn demand_coerce_diag(
         (expected, Some(err))
     }
 
+    /// Like `demand_coerce`, but for the value `expr` of a const argument for the const parameter
+    /// `param_def_id`, which also points at the parameter if the types don't match.
+    pub fn demand_const_arg_coerce(
+        &self,
+        expr: &hir::Expr<'_>,
+        checked_ty: Ty<'tcx>,
+        expected: Ty<'tcx>,
+        param_def_id: DefId,
+    ) -> Ty<'tcx> {
+        let (ty, err) = self.demand_coerce_diag(expr, checked_ty, expected, None, AllowTwoPhase::No);
+        if let Some(mut err) = err {
+            let expected = self.resolve_vars_with_obligations(expected);
+            let found = self.resolve_vars_with_obligations(checked_ty);
+            err.set_primary_message(format!(
+                "expected const argument of type `{}`, found `{}`",
+                expected, found
+            ));
+            err.span_note(
+                self.tcx.def_span(param_def_id),
+                &format!("const parameter `{}` declared here", self.tcx.item_name(param_def_id)),
+            );
+            err.emit();
+        }
+        ty
+    }
+
     fn annotate_expected_due_to_let_ty(
         &self,
         err: &mut DiagnosticBuilder<'_>,
@@ -555,7 +555,18 @@ fn typeck_with_fallback<'tcx>(
             // Gather locals in statics (because of block expressions).
             GatherLocalsVisitor::new(&fcx, id).visit_body(body);
 
-            fcx.check_expr_coercable_to_type(&body.value, revealed_ty, None);
+            // Const arguments get a dedicated diagnostic when their type doesn't match the type
+            // of their parameter, e.g. in `foo::<true>()` for `fn foo<const X: u32>()`.
+            let const_param = match tcx.hir().get(id) {
+                Node::AnonConst(_) => tcx.opt_const_param_of(def_id),
+                _ => None,
+            };
+            if let Some(param_def_id) = const_param {
+                let ty = fcx.check_expr_with_hint(&body.value, revealed_ty);
+                fcx.demand_const_arg_coerce(&body.value, ty, revealed_ty, param_def_id);
+            } else {
+                fcx.check_expr_coercable_to_type(&body.value, revealed_ty, None);
+            }
 
             fcx.write_ty(id, revealed_ty);
 
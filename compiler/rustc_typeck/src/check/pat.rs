@@ -186,7 +186,7 @@ fn check_pat(
                 expected
             }
             PatKind::Tuple(elements, ddpos) => {
-                self.check_pat_tuple(pat.span, elements, ddpos, expected, def_bm, ti)
+                self.check_pat_tuple(pat, elements, ddpos, expected, def_bm, ti)
             }
             PatKind::Box(inner) => self.check_pat_box(pat.span, inner, expected, def_bm, ti),
             PatKind::Ref(inner, mutbl) => {
@@ -960,7 +960,7 @@ fn check_pat_tuple_struct(
             }
         } else {
             // Pattern has wrong number of fields.
-            self.e0023(pat.span, res, qpath, subpats, &variant.fields, expected, had_err);
+            self.e0023(pat, res, qpath, subpats, &variant.fields, expected, had_err);
             on_error();
             return tcx.ty_error();
         }
@@ -969,7 +969,7 @@ fn check_pat_tuple_struct(
 
     fn e0023(
         &self,
-        pat_span: Span,
+        pat: &Pat<'_>,
         res: Res,
         qpath: &hir::QPath<'_>,
         subpats: &'tcx [&'tcx Pat<'tcx>],
@@ -977,22 +977,45 @@ fn e0023(
         expected: Ty<'tcx>,
         had_err: bool,
     ) {
+        let pat_span = pat.span;
         let subpats_ending = pluralize!(subpats.len());
         let fields_ending = pluralize!(fields.len());
         let res_span = self.tcx.def_span(res.def_id());
-        let mut err = struct_span_err!(
-            self.tcx.sess,
-            pat_span,
-            E0023,
-            "this pattern has {} field{}, but the corresponding {} has {} field{}",
-            subpats.len(),
-            subpats_ending,
-            res.descr(),
-            fields.len(),
-            fields_ending,
-        );
+        let (span, mut err) = if self.is_destructuring_assignment_pat(pat) {
+            // The pattern is the left-hand side of an assignment, so point at the assignees
+            // that have no corresponding field, if there are any.
+            let span = if subpats.len() > fields.len() {
+                subpats[fields.len()].span.to(subpats[subpats.len() - 1].span)
+            } else {
+                pat_span
+            };
+            let err = struct_span_err!(
+                self.tcx.sess,
+                span,
+                E0023,
+                "this {} has {} field{}, but the pattern has {}",
+                res.descr(),
+                fields.len(),
+                fields_ending,
+                subpats.len(),
+            );
+            (span, err)
+        } else {
+            let err = struct_span_err!(
+                self.tcx.sess,
+                pat_span,
+                E0023,
+                "this pattern has {} field{}, but the corresponding {} has {} field{}",
+                subpats.len(),
+                subpats_ending,
+                res.descr(),
+                fields.len(),
+                fields_ending,
+            );
+            (pat_span, err)
+        };
         err.span_label(
-            pat_span,
+            span,
             format!("expected {} field{}, found {}", fields.len(), fields_ending, subpats.len(),),
         )
         .span_label(res_span, format!("{} defined here", res.descr()));
@@ -1045,9 +1068,25 @@ fn e0023(
         err.emit();
     }
 
+    /// Returns whether `pat` is part of the left-hand side of a destructuring assignment,
+    /// i.e. of the pattern of a `let` introduced by lowering the assignment.
+    fn is_destructuring_assignment_pat(&self, pat: &Pat<'_>) -> bool {
+        let hir = self.tcx.hir();
+        let mut hir_id = pat.hir_id;
+        loop {
+            match hir.find(hir.get_parent_node(hir_id)) {
+                Some(hir::Node::Pat(parent)) => hir_id = parent.hir_id,
+                Some(hir::Node::Local(local)) => {
+                    return matches!(local.source, hir::LocalSource::AssignDesugar(_));
+                }
+                _ => return false,
+            }
+        }
+    }
+
     fn check_pat_tuple(
         &self,
-        span: Span,
+        pat: &'tcx Pat<'tcx>,
         elements: &'tcx [&'tcx Pat<'tcx>],
         ddpos: Option<usize>,
         expected: Ty<'tcx>,
@@ -1055,6 +1094,18 @@ fn check_pat_tuple(
         ti: TopInfo<'tcx>,
     ) -> Ty<'tcx> {
         let tcx = self.tcx;
+        let span = pat.span;
+        if ddpos.is_none() && self.is_destructuring_assignment_pat(pat) {
+            if let ty::Tuple(tys) = self.resolve_vars_with_obligations(expected).kind() {
+                if tys.len() != elements.len() {
+                    self.e0308_tuple_assignment_arity(pat, elements.len(), tys.len(), ti);
+                    for elem in elements {
+                        self.check_pat(elem, tcx.ty_error(), def_bm, ti);
+                    }
+                    return tcx.ty_error();
+                }
+            }
+        }
         let mut expected_len = elements.len();
         if ddpos.is_some() {
             // Require known type only when `..` is present.
@@ -1090,6 +1141,33 @@ fn check_pat_tuple(
         }
     }
 
+    /// Reports a tuple on the left-hand side of a destructuring assignment that has a different
+    /// number of elements than the tuple assigned to it. Unlike the generic type mismatch, this
+    /// takes the assignee's side: for `(a, b) = (1, 2, 3)`, it expected a 2-element tuple.
+    fn e0308_tuple_assignment_arity(
+        &self,
+        pat: &Pat<'_>,
+        assignees: usize,
+        elements: usize,
+        ti: TopInfo<'tcx>,
+    ) {
+        let mut err = struct_span_err!(self.tcx.sess, pat.span, E0308, "mismatched types");
+        err.span_label(
+            pat.span,
+            format!("expected a {}-element tuple, found a {}-element tuple", assignees, elements),
+        );
+        if let Some(span) = ti.span {
+            err.span_label(
+                span,
+                format!(
+                    "this expression has type `{}`",
+                    self.resolve_vars_if_possible(ti.expected)
+                ),
+            );
+        }
+        err.emit();
+    }
+
     fn check_struct_pat_fields(
         &self,
         adt_ty: Ty<'tcx>,
@@ -12,6 +12,7 @@
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::ItemKind;
 use rustc_middle::hir::map as hir_map;
+use rustc_middle::middle::resolve_lifetime as rl;
 use rustc_middle::ty::subst::{GenericArgKind, InternalSubsts, Subst};
 use rustc_middle::ty::trait_def::TraitSpecializationKind;
 use rustc_middle::ty::{
@@ -184,8 +185,12 @@ pub fn check_item_well_formed(tcx: TyCtxt<'_>, def_id: LocalDefId) {
 
             check_variances_for_type_defn(tcx, item, ast_generics);
         }
-        hir::ItemKind::Trait(..) => {
+        hir::ItemKind::Trait(_, _, ref ast_generics, ..) => {
             check_trait(tcx, item);
+
+            if tcx.sess.opts.debugging_opts.strict_unused_lifetimes {
+                check_unused_trait_lifetimes(tcx, item, ast_generics);
+            }
         }
         hir::ItemKind::TraitAlias(..) => {
             check_trait(tcx, item);
@@ -287,7 +292,7 @@ fn check_param_wf(tcx: TyCtxt<'_>, param: &hir::GenericParam<'_>) {
 
         // Const parameters are well formed if their
         // type is structural match.
-        hir::GenericParamKind::Const { ty: hir_ty } => {
+        hir::GenericParamKind::Const { ty: hir_ty, default: _ } => {
             let ty = tcx.type_of(tcx.hir().local_def_id(param.hir_id));
 
             let err_ty_str;
@@ -724,7 +729,8 @@ fn check_where_clauses<'tcx, 'fcx>(
     let generics = tcx.generics_of(def_id);
 
     let is_our_default = |def: &ty::GenericParamDef| match def.kind {
-        GenericParamDefKind::Type { has_default, .. } => {
+        GenericParamDefKind::Type { has_default, .. }
+        | GenericParamDefKind::Const { has_default } => {
             has_default && def.index >= generics.parent_count as u32
         }
         _ => unreachable!(),
@@ -737,20 +743,36 @@ fn check_where_clauses<'tcx, 'fcx>(
     //
     // Here, the default `Vec<[u32]>` is not WF because `[u32]: Sized` does not hold.
     for param in &generics.params {
-        if let GenericParamDefKind::Type { .. } = param.kind {
-            if is_our_default(&param) {
-                let ty = fcx.tcx.type_of(param.def_id);
-                // Ignore dependent defaults -- that is, where the default of one type
-                // parameter includes another (e.g., `<T, U = T>`). In those cases, we can't
-                // be sure if it will error or not as user might always specify the other.
-                if !ty.needs_subst() {
-                    fcx.register_wf_obligation(
-                        ty.into(),
-                        fcx.tcx.def_span(param.def_id),
-                        ObligationCauseCode::MiscObligation,
-                    );
+        match param.kind {
+            GenericParamDefKind::Type { .. } => {
+                if is_our_default(&param) {
+                    let ty = fcx.tcx.type_of(param.def_id);
+                    // Ignore dependent defaults -- that is, where the default of one type
+                    // parameter includes another (e.g., `<T, U = T>`). In those cases, we can't
+                    // be sure if it will error or not as user might always specify the other.
+                    if !ty.needs_subst() {
+                        fcx.register_wf_obligation(
+                            ty.into(),
+                            fcx.tcx.def_span(param.def_id),
+                            ObligationCauseCode::MiscObligation,
+                        );
+                    }
                 }
             }
+            GenericParamDefKind::Const { .. } => {
+                if is_our_default(&param) {
+                    let default_ct = fcx.tcx.const_param_default(param.def_id);
+                    // As with types, ignore defaults which depend on other parameters.
+                    if !default_ct.needs_subst() {
+                        fcx.register_wf_obligation(
+                            default_ct.into(),
+                            fcx.tcx.def_span(param.def_id),
+                            ObligationCauseCode::MiscObligation,
+                        );
+                    }
+                }
+            }
+            GenericParamDefKind::Lifetime => {}
         }
     }
 
@@ -783,8 +805,17 @@ fn check_where_clauses<'tcx, 'fcx>(
                 fcx.tcx.mk_param_from_def(param)
             }
 
-            GenericParamDefKind::Const => {
-                // FIXME(const_generics:defaults)
+            GenericParamDefKind::Const { .. } => {
+                // If the param has a default, ...
+                if is_our_default(param) {
+                    let default_ct = fcx.tcx.const_param_default(param.def_id);
+                    // ... and it's not a dependent default, ...
+                    if !default_ct.needs_subst() {
+                        // ... then substitute it with the default.
+                        return default_ct.into();
+                    }
+                }
+
                 fcx.tcx.mk_param_from_def(param)
             }
         }
@@ -1272,13 +1303,14 @@ fn check_variances_for_type_defn<'tcx>(
 
         match param.name {
             hir::ParamName::Error => {}
-            _ => report_bivariance(tcx, param.span, param.name.ident().name),
+            _ => report_bivariance(tcx, param.span, param.name.ident().name, item.ident.span),
         }
     }
 }
 
-fn report_bivariance(tcx: TyCtxt<'_>, span: Span, param_name: Symbol) {
+fn report_bivariance(tcx: TyCtxt<'_>, span: Span, param_name: Symbol, item_span: Span) {
     let mut err = error_392(tcx, span, param_name);
+    err.span_label(item_span, "in this definition");
 
     let suggested_marker_id = tcx.lang_items().phantom_data();
     // Help is available only in presence of lang items.
@@ -1295,6 +1327,60 @@ fn report_bivariance(tcx: TyCtxt<'_>, span: Span, param_name: Symbol) {
     err.emit();
 }
 
+/// Reports the lifetime parameters of a trait that the trait never refers to, like
+/// `check_variances_for_type_defn` does for types. Only done under
+/// `-Z strict-unused-lifetimes`, as such parameters are allowed on traits.
+///
+/// Variance is not computed for traits, so instead this looks for uses of the lifetimes in the
+/// trait's bounds and where clauses and in its items.
+fn check_unused_trait_lifetimes<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    item: &'tcx hir::Item<'tcx>,
+    hir_generics: &hir::Generics<'_>,
+) {
+    struct LifetimeUses<'tcx> {
+        tcx: TyCtxt<'tcx>,
+        used: FxHashSet<DefId>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for LifetimeUses<'tcx> {
+        type Map = hir_map::Map<'tcx>;
+
+        fn nested_visit_map(&mut self) -> hir_visit::NestedVisitorMap<Self::Map> {
+            hir_visit::NestedVisitorMap::All(self.tcx.hir())
+        }
+
+        fn visit_lifetime(&mut self, lifetime: &'tcx hir::Lifetime) {
+            if let Some(rl::Region::EarlyBound(_, def_id, _)) =
+                self.tcx.named_region(lifetime.hir_id)
+            {
+                self.used.insert(def_id);
+            }
+        }
+    }
+
+    let mut uses = LifetimeUses { tcx, used: FxHashSet::default() };
+    uses.visit_item(item);
+
+    for param in hir_generics.params {
+        if !matches!(param.kind, hir::GenericParamKind::Lifetime { .. }) {
+            continue;
+        }
+        if uses.used.contains(&tcx.hir().local_def_id(param.hir_id).to_def_id()) {
+            continue;
+        }
+
+        let param_name = param.name.ident().name;
+        let mut err = error_392(tcx, param.span, param_name);
+        err.span_label(item.ident.span, "in this definition");
+        err.help(&format!(
+            "consider removing `{}` or referring to it in the bounds or items of the trait",
+            param_name
+        ));
+        err.emit();
+    }
+}
+
 /// Feature gates RFC 2056 -- trivial bounds, checking for global bounds that
 /// aren't true.
 fn check_false_global_bounds(fcx: &FnCtxt<'_, '_>, span: Span, id: hir::HirId) {
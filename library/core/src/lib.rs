@@ -79,9 +79,12 @@
 #![feature(const_int_unchecked_arith)]
 #![feature(const_mut_refs)]
 #![feature(const_int_pow)]
+#![feature(const_intrinsic_copy)]
 #![feature(constctlz)]
 #![feature(const_cttz)]
 #![feature(const_panic)]
+#![feature(const_ptr_write)]
+#![feature(const_raw_ptr_deref)]
 #![feature(const_pin)]
 #![feature(const_fn)]
 #![feature(const_fn_union)]
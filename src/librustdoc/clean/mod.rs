@@ -424,15 +424,18 @@ fn clean(&self, _: &DocContext<'_>) -> Lifetime {
 }
 
 impl Clean<Constant> for hir::ConstArg {
+    fn clean(&self, cx: &DocContext<'_>) -> Constant {
+        self.value.clean(cx)
+    }
+}
+
+impl Clean<Constant> for hir::AnonConst {
     fn clean(&self, cx: &DocContext<'_>) -> Constant {
         Constant {
-            type_: cx
-                .tcx
-                .type_of(cx.tcx.hir().body_owner_def_id(self.value.body).to_def_id())
-                .clean(cx),
-            expr: print_const_expr(cx, self.value.body),
+            type_: cx.tcx.type_of(cx.tcx.hir().body_owner_def_id(self.body).to_def_id()).clean(cx),
+            expr: print_const_expr(cx, self.body),
             value: None,
-            is_literal: is_literal_expr(cx, self.value.body.hir_id),
+            is_literal: is_literal_expr(cx, self.body.hir_id),
         }
     }
 }
@@ -585,11 +588,16 @@ fn clean(&self, cx: &DocContext<'_>) -> GenericParamDef {
                     },
                 )
             }
-            ty::GenericParamDefKind::Const { .. } => (
+            ty::GenericParamDefKind::Const { has_default } => (
                 self.name.clean(cx),
                 GenericParamDefKind::Const {
                     did: self.def_id,
                     ty: cx.tcx.type_of(self.def_id).clean(cx),
+                    default: if has_default {
+                        Some(cx.tcx.const_param_default(self.def_id).to_string())
+                    } else {
+                        None
+                    },
                 },
             ),
         };
@@ -627,11 +635,12 @@ fn clean(&self, cx: &DocContext<'_>) -> GenericParamDef {
                     synthetic,
                 },
             ),
-            hir::GenericParamKind::Const { ref ty } => (
+            hir::GenericParamKind::Const { ref ty, default } => (
                 self.name.ident().name.clean(cx),
                 GenericParamDefKind::Const {
                     did: cx.tcx.hir().local_def_id(self.hir_id).to_def_id(),
                     ty: ty.clean(cx),
+                    default: default.map(|ct| print_const_expr(cx, ct.body)),
                 },
             ),
         };
@@ -1388,7 +1397,7 @@ fn clean(&self, cx: &DocContext<'_>) -> Type {
                                     }
                                     indices.types += 1;
                                 }
-                                hir::GenericParamKind::Const { .. } => {
+                                hir::GenericParamKind::Const { ref default, .. } => {
                                     let const_param_def_id =
                                         cx.tcx.hir().local_def_id(param.hir_id);
                                     let mut j = 0;
@@ -1406,8 +1415,12 @@ fn clean(&self, cx: &DocContext<'_>) -> Type {
                                     if let Some(ct) = const_ {
                                         ct_substs
                                             .insert(const_param_def_id.to_def_id(), ct.clean(cx));
+                                    } else if let Some(default) = default {
+                                        ct_substs.insert(
+                                            const_param_def_id.to_def_id(),
+                                            default.clean(cx),
+                                        );
                                     }
-                                    // FIXME(const_generics:defaults)
                                     indices.consts += 1;
                                 }
                             }
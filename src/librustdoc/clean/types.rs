@@ -888,6 +888,7 @@ impl WherePredicate {
     Const {
         did: DefId,
         ty: Type,
+        default: Option<String>,
     },
 }
 
@@ -0,0 +1,19 @@
+// Verify that the assignments generated for a destructuring assignment share the line of the
+// original statement, but get the column of their own element.
+//
+// ignore-windows
+// compile-flags: -C debuginfo=2 -C panic=abort
+
+#![crate_type = "lib"]
+#![feature(destructuring_assignment)]
+
+pub fn assign(mut first: String, mut second: String, x: String, y: String) -> (String, String) {
+    // Assigning to an initialized `String` drops the old value at the assignment's location.
+    // CHECK: call void @{{.*}}drop_in_place{{.*}}, !dbg [[A:!.*]]
+    // CHECK: call void @{{.*}}drop_in_place{{.*}}, !dbg [[B:!.*]]
+    (first, second) = (x, y);
+
+    // CHECK: [[A]] = !DILocation(line: 14, column: 6,
+    // CHECK: [[B]] = !DILocation(line: 14, column: 13,
+    (first, second)
+}
@@ -0,0 +1,26 @@
+// compile-flags: -C no-prepopulate-passes
+// ignore-arm
+// ignore-aarch64
+// (on ARM, niche variants are written with a `memset` first, see #34427)
+
+#![crate_type = "lib"]
+
+// Writing the dataful variant of a niche-optimized enum only stores its field;
+// there is no tag to write.
+// CHECK-LABEL: @write_some
+#[no_mangle]
+pub fn write_some<'a>(out: &mut Option<&'a u8>, x: &'a u8) {
+    // CHECK-NOT: store i{{[0-9]+}} {{[0-9]+}}
+    // CHECK: ret void
+    *out = Some(x);
+}
+
+// Writing a niche variant stores the niche value into the niche field, and nothing else.
+// CHECK-LABEL: @write_none
+#[no_mangle]
+pub fn write_none(out: &mut Option<&u8>) {
+    // CHECK: store {{.*}} null
+    // CHECK-NOT: store
+    // CHECK: ret void
+    *out = None;
+}
@@ -0,0 +1,26 @@
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+pub struct Foo {
+    bar: Bar,
+    padding: [u32; 8],
+}
+
+pub struct Bar {
+    x: u32,
+    baz: u32,
+}
+
+// Repeated assignments to the same field of a local within a basic block
+// should reuse the address computed for the first assignment.
+// CHECK-LABEL: @assign_twice
+#[no_mangle]
+pub fn assign_twice(mut foo: Foo) -> Foo {
+    // CHECK: store i32 1, i32* [[PTR:%[a-z0-9._]+]]
+    // CHECK-NOT: getelementptr
+    // CHECK: store i32 2, i32* [[PTR]]
+    foo.bar.baz = 1;
+    foo.bar.baz = 2;
+    foo
+}
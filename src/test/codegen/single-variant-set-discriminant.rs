@@ -0,0 +1,17 @@
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+pub enum Single {
+    Only(u32, u32),
+}
+
+// Initializing the only variant of an enum should not store a discriminant,
+// since the layout doesn't have one.
+// CHECK-LABEL: @make_single
+#[no_mangle]
+pub fn make_single(a: u32, b: u32) -> Single {
+    // CHECK-NOT: store i{{[0-9]+}} 0
+    // CHECK: ret
+    Single::Only(a, b)
+}
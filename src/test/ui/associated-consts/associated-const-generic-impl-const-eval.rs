@@ -0,0 +1,39 @@
+// run-pass
+// Check that const-eval evaluates associated consts of generic impls with the impl's type
+// parameters substituted, also when they are reached through another generic item.
+
+use std::marker::PhantomData;
+use std::mem;
+
+trait Foo {
+    const N: usize;
+}
+
+impl<T> Foo for Vec<T> {
+    const N: usize = mem::size_of::<T>();
+}
+
+impl<T: Foo, U: Foo> Foo for (T, U) {
+    const N: usize = T::N * 10 + U::N;
+}
+
+struct Get<T>(PhantomData<T>);
+
+impl<T: Foo> Get<T> {
+    const N: usize = T::N;
+}
+
+const VEC_U8: usize = <Vec<u8> as Foo>::N;
+const VEC_U64: usize = <Vec<u64> as Foo>::N;
+const PAIR: usize = Get::<(Vec<u16>, Vec<u32>)>::N;
+const NESTED: usize = Get::<((Vec<u8>, Vec<u8>), Vec<u64>)>::N;
+
+fn main() {
+    assert_eq!(VEC_U8, 1);
+    assert_eq!(VEC_U64, 8);
+    assert_eq!(PAIR, 24);
+    assert_eq!(NESTED, 118);
+
+    let array = [0u8; <(Vec<u8>, Vec<u16>) as Foo>::N];
+    assert_eq!(array.len(), 12);
+}
@@ -0,0 +1,21 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::collections::HashMap;
+
+fn round_trip<const N: usize>(value: [u8; N]) -> [u8; N] {
+    let mut map: HashMap<&str, [u8; N]> = HashMap::new();
+    map.insert("key", value);
+    map.insert("other", [0; N]);
+    map["key"]
+}
+
+fn main() {
+    assert_eq!(round_trip([1, 2, 3]), [1, 2, 3]);
+    assert_eq!(round_trip([7; 32]), [7; 32]);
+    assert_eq!(round_trip([]), []);
+}
@@ -0,0 +1,21 @@
+// run-pass
+// revisions: full min
+// Checks that a const parameter flows through `array::map`.
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+#![feature(array_map)]
+
+fn widen<const N: usize>(arr: [u8; N]) -> [u16; N] {
+    arr.map(|x| x as u16 * 256)
+}
+
+fn main() {
+    let empty: [u16; 0] = widen([]);
+    assert_eq!(empty, []);
+    assert_eq!(widen([1]), [256]);
+    assert_eq!(widen([1, 2, 3]), [256, 512, 768]);
+    let big = widen([255; 32]);
+    assert_eq!(big.len(), 32);
+    assert!(big.iter().all(|&x| x == 65280));
+}
@@ -0,0 +1,28 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+fn sum<const N: usize>(arr: [u8; N]) -> u32 {
+    let mut total = 0;
+    for x in &arr {
+        total += *x as u32;
+    }
+    total
+}
+
+fn count<const N: usize>(arr: &[u8; N]) -> usize {
+    arr.into_iter().count()
+}
+
+fn main() {
+    assert_eq!(sum([]), 0);
+    assert_eq!(sum([7]), 7);
+    assert_eq!(sum([1, 2, 3]), 6);
+    assert_eq!(sum([255; 64]), 255 * 64);
+
+    assert_eq!(count(&[]), 0);
+    assert_eq!(count(&[0; 5]), 5);
+}
@@ -0,0 +1,51 @@
+// Check that `bool` and `char` const parameters of functions can be given with a turbofish and
+// that each value gets its own instantiation.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+fn branch<const B: bool>() -> &'static str {
+    if B { "yes" } else { "no" }
+}
+
+fn classify<const C: char>() -> u32 {
+    match C {
+        'a'..='z' => 1,
+        'A'..='Z' => 2,
+        '0'..='9' => 3,
+        '\u{0}' => 4,
+        _ => 5,
+    }
+}
+
+struct Flagged<const B: bool, const C: char>;
+
+impl<const B: bool, const C: char> Flagged<B, C> {
+    fn describe(&self) -> (bool, char) {
+        (B, C)
+    }
+}
+
+fn main() {
+    assert_eq!(branch::<true>(), "yes");
+    assert_eq!(branch::<false>(), "no");
+    assert_eq!(branch::<{ 1 > 2 }>(), "no");
+
+    assert_eq!(classify::<'q'>(), 1);
+    assert_eq!(classify::<'Q'>(), 2);
+    assert_eq!(classify::<'7'>(), 3);
+    assert_eq!(classify::<'\0'>(), 4);
+    assert_eq!(classify::<'ß'>(), 5);
+    assert_eq!(classify::<{ char::MAX }>(), 5);
+
+    // Each value is a distinct instantiation, even behind function pointers.
+    let fns: [fn() -> &'static str; 2] = [branch::<false>, branch::<true>];
+    assert_eq!(fns[0](), "no");
+    assert_eq!(fns[1](), "yes");
+
+    assert_eq!(Flagged::<true, 'x'>.describe(), (true, 'x'));
+    assert_eq!(Flagged::<false, '\u{10FFFF}'>.describe(), (false, '\u{10FFFF}'));
+}
@@ -0,0 +1,27 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+trait Drawable<const N: usize> {
+    fn draw(&self) -> [char; N];
+}
+
+struct Dot(char);
+
+impl<const N: usize> Drawable<N> for Dot {
+    fn draw(&self) -> [char; N] {
+        [self.0; N]
+    }
+}
+
+fn main() {
+    let shapes: Vec<Box<dyn Drawable<3>>> = vec![Box::new(Dot('x')), Box::new(Dot('o'))];
+    let drawn: Vec<[char; 3]> = shapes.iter().map(|shape| shape.draw()).collect();
+    assert_eq!(drawn, [['x'; 3], ['o'; 3]]);
+
+    let empty: Box<dyn Drawable<0>> = Box::new(Dot('-'));
+    assert_eq!(empty.draw(), []);
+}
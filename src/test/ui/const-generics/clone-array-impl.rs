@@ -0,0 +1,25 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+struct Buf<const N: usize>([u8; N]);
+
+impl<const N: usize> Clone for Buf<N> {
+    fn clone(&self) -> Self {
+        Buf(self.0)
+    }
+}
+
+fn main() {
+    let mut a = Buf([1, 2, 3, 4, 5]);
+    let b = a.clone();
+    a.0[0] = 10;
+    assert_eq!(a.0, [10, 2, 3, 4, 5]);
+    assert_eq!(b.0, [1, 2, 3, 4, 5]);
+
+    let empty = Buf([]);
+    assert_eq!(empty.clone().0, []);
+}
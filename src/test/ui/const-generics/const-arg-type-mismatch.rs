@@ -0,0 +1,12 @@
+// Checks that a const argument whose type doesn't match its parameter's type points at the
+// parameter.
+#![feature(min_const_generics)]
+
+fn foo<const X: u32>() {}
+
+fn main() {
+    foo::<true>();
+    //~^ ERROR expected const argument of type `u32`, found `bool`
+    foo::<'a'>();
+    //~^ ERROR expected const argument of type `u32`, found `char`
+}
@@ -0,0 +1,14 @@
+// Overflow while evaluating a const argument is a hard error at the argument.
+#![feature(const_generics)]
+#![allow(incomplete_features)]
+
+const BIG: u8 = 255;
+
+fn foo<const N: u8>() -> u8 {
+    N
+}
+
+fn main() {
+    foo::<{ BIG + 1 }>();
+    //~^ ERROR evaluation of constant value failed
+}
@@ -0,0 +1,42 @@
+// run-pass
+// Braced const expressions are accepted as const arguments and evaluate to the same
+// instantiation as the equivalent literal.
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+const SIZE: usize = 17;
+
+struct Buf<const N: usize>([u8; N]);
+
+fn len<const N: usize>() -> usize {
+    N
+}
+
+fn zeroed<const N: usize>() -> Buf<N> {
+    Buf([0; N])
+}
+
+trait Len {
+    const LEN: usize;
+}
+
+impl Len for u16 {
+    const LEN: usize = 9;
+}
+
+fn main() {
+    assert_eq!(len::<18>(), 18);
+    assert_eq!(len::<{ 2 * 9 }>(), 18);
+    assert_eq!(len::<{ SIZE + 1 }>(), 18);
+    assert_eq!(len::<{ <u16 as Len>::LEN * 2 }>(), 18);
+    assert_eq!(len::<{ if SIZE > 10 { SIZE - 10 } else { 0 } }>(), 7);
+
+    // All of these are the same type.
+    let a: Buf<18> = zeroed::<{ 2 * 9 }>();
+    let b: Buf<{ SIZE + 1 }> = a;
+    let c: Buf<{ 36 / 2 }> = b;
+    assert_eq!(c.0.len(), 18);
+}
@@ -0,0 +1,20 @@
+// Check that a failing assertion on a const parameter inside a `const fn` is reported when
+// the `const fn` is evaluated, and that the backtrace names the value of the parameter.
+#![feature(min_const_generics, const_panic)]
+#![crate_type = "lib"]
+
+const fn nonzero<const N: usize>() -> usize {
+    assert!(N > 0); //~ ERROR any use of this value will cause an error
+    N
+}
+
+const fn double<const N: usize>(expected: usize) -> usize {
+    assert!(N * 2 == expected); //~ ERROR any use of this value will cause an error
+    N * 2
+}
+
+const ZERO: usize = nonzero::<0>();
+
+const SIX: usize = double::<3>(6);
+
+const SEVEN: usize = double::<3>(7);
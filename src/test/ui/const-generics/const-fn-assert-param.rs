@@ -0,0 +1,34 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+#![feature(const_panic)]
+
+const fn nonzero<const N: usize>() -> usize {
+    assert!(N > 0);
+    N
+}
+
+const fn triangle<const N: usize>() -> usize {
+    let mut sum = 0;
+    let mut i = 0;
+    while i <= N {
+        sum += i;
+        i += 1;
+    }
+    // `assert_eq!` can't format its operands during const evaluation, so compare directly.
+    assert!(sum == N * (N + 1) / 2);
+    sum
+}
+
+const FOUR: usize = nonzero::<4>();
+const TEN: usize = triangle::<4>();
+
+fn main() {
+    assert_eq!(FOUR, 4);
+    assert_eq!(TEN, 10);
+    assert_eq!(nonzero::<7>(), 7);
+    assert_eq!(triangle::<10>(), 55);
+}
@@ -0,0 +1,40 @@
+// Checks that comparisons between const params can be used to select a branch
+// when evaluating a `const fn` in a const context.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::cmp::Ordering;
+
+// `Ord::cmp` cannot be called in a `const fn` yet, so spell it out.
+const fn cmp(a: usize, b: usize) -> Ordering {
+    if a < b {
+        Ordering::Less
+    } else if a > b {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+const fn pick<const N: usize, const M: usize>() -> [u8; 2] {
+    match cmp(N, M) {
+        Ordering::Less => [0, 1],
+        Ordering::Equal => [1, 1],
+        Ordering::Greater => [1, 0],
+    }
+}
+
+const LESS: [u8; 2] = pick::<1, 2>();
+const EQUAL: [u8; 2] = pick::<3, 3>();
+const GREATER: [u8; 2] = pick::<5, 4>();
+
+fn main() {
+    assert_eq!(LESS, [0, 1]);
+    assert_eq!(EQUAL, [1, 1]);
+    assert_eq!(GREATER, [1, 0]);
+    assert_eq!(pick::<0, 0>(), [1, 1]);
+}
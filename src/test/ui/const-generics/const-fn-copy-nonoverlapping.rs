@@ -0,0 +1,34 @@
+// Check that `ptr::copy_nonoverlapping` can be const-evaluated with a count that is a
+// const parameter.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+#![feature(const_intrinsic_copy, const_mut_refs)]
+
+use std::ptr;
+
+const fn copied<const N: usize>(src: [u8; N]) -> [u8; N] {
+    let mut dst = [0; N];
+    unsafe {
+        ptr::copy_nonoverlapping(
+            &src as *const [u8; N] as *const u8,
+            &mut dst as *mut [u8; N] as *mut u8,
+            N,
+        );
+    }
+    dst
+}
+
+const THREE: [u8; 3] = copied([1, 2, 3]);
+const EIGHT: [u8; 8] = copied([7; 8]);
+const EMPTY: [u8; 0] = copied([]);
+
+fn main() {
+    assert_eq!(THREE, [1, 2, 3]);
+    assert_eq!(EIGHT, [7; 8]);
+    assert_eq!(EMPTY, []);
+    assert_eq!(copied([4, 5]), [4, 5]);
+}
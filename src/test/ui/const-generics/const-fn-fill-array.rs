@@ -0,0 +1,34 @@
+// Check that a `const fn` filling an array whose length is a const parameter can be
+// evaluated at compile time.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+const fn ones<const N: usize>() -> [u8; N] {
+    let mut arr = [0; N];
+    let mut i = 0;
+    while i < N {
+        arr[i] = 1;
+        i += 1;
+    }
+    arr
+}
+
+const fn nested<const N: usize, const M: usize>() -> [[u8; N]; M] {
+    [ones::<N>(); M]
+}
+
+const TEN: [u8; 10] = ones::<10>();
+const EMPTY: [u8; 0] = ones::<0>();
+const LARGE: [u8; 1000] = ones::<1000>();
+const GRID: [[u8; 3]; 2] = nested::<3, 2>();
+
+fn main() {
+    assert_eq!(TEN, [1; 10]);
+    assert_eq!(EMPTY, []);
+    assert!(LARGE.iter().all(|&x| x == 1));
+    assert_eq!(GRID, [[1; 3]; 2]);
+}
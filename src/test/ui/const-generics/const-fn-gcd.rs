@@ -0,0 +1,29 @@
+// run-pass
+// revisions: full min
+// Checks that a `const fn` can loop over values derived from two const parameters.
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+const fn gcd<const N: u32, const M: u32>() -> u32 {
+    let (mut a, mut b) = (N, M);
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+const G: u32 = gcd::<12, 18>();
+
+fn main() {
+    assert_eq!(G, 6);
+    assert_eq!(gcd::<18, 12>(), 6);
+    assert_eq!(gcd::<17, 5>(), 1);
+    assert_eq!(gcd::<0, 7>(), 7);
+    assert_eq!(gcd::<7, 0>(), 7);
+    assert_eq!(gcd::<0, 0>(), 0);
+    let arr = [0u8; gcd::<12, 18>() as usize];
+    assert_eq!(arr.len(), 6);
+}
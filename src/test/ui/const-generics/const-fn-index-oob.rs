@@ -0,0 +1,13 @@
+// revisions: full min
+// Checks that indexing out of bounds of a const-generic array is caught during const-eval.
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+const fn get<const N: usize>(arr: [u8; N], i: usize) -> u8 {
+    arr[i] //~ ERROR any use of this value will cause an error
+}
+
+const OOB: u8 = get([1, 2, 3], 5);
+
+fn main() {}
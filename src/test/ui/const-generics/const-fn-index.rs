@@ -0,0 +1,21 @@
+// run-pass
+// revisions: full min
+// Checks that a `const fn` can index into an array whose length is a const parameter.
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+const fn get<const N: usize>(arr: [u8; N], i: usize) -> u8 {
+    arr[i]
+}
+
+const FIRST: u8 = get([1, 2, 3], 0);
+const LAST: u8 = get([1, 2, 3], 2);
+const SINGLE: u8 = get([7], 0);
+
+fn main() {
+    assert_eq!(FIRST, 1);
+    assert_eq!(LAST, 3);
+    assert_eq!(SINGLE, 7);
+    let _: [(); get([4, 5], 1) as usize] = [(); 5];
+}
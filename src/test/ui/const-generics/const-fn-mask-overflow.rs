@@ -0,0 +1,13 @@
+// revisions: full min
+// Checks that shifting by a const parameter that is too large is caught during const-eval.
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+const fn mask<const N: u32>() -> u64 {
+    (1u64 << N) - 1 //~ ERROR any use of this value will cause an error
+}
+
+const FULL: u64 = mask::<64>();
+
+fn main() {}
@@ -0,0 +1,23 @@
+// run-pass
+// revisions: full min
+// Checks that a `const fn` can shift by a const parameter.
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+const fn mask<const N: u32>() -> u64 {
+    (1u64 << N) - 1
+}
+
+const EMPTY: u64 = mask::<0>();
+const BYTE: u64 = mask::<8>();
+const ALL_BUT_TOP: u64 = mask::<63>();
+
+fn main() {
+    assert_eq!(EMPTY, 0);
+    assert_eq!(BYTE, 0xff);
+    assert_eq!(ALL_BUT_TOP, u64::MAX >> 1);
+    let arr = [0u8; mask::<4>() as usize];
+    assert_eq!(arr.len(), 15);
+    assert_eq!(mask::<32>(), u32::MAX as u64);
+}
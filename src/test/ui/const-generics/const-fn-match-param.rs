@@ -0,0 +1,24 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+const fn pick<const N: usize>() -> u8 {
+    match N {
+        0 => 1,
+        1..=3 => 2,
+        _ => 3,
+    }
+}
+
+const ZERO: u8 = pick::<0>();
+const TWO: u8 = pick::<2>();
+const FIVE: u8 = pick::<5>();
+
+fn main() {
+    assert_eq!(ZERO, 1);
+    assert_eq!(TWO, 2);
+    assert_eq!(FIVE, 3);
+}
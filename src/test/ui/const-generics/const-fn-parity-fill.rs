@@ -0,0 +1,23 @@
+// run-pass
+// revisions: full min
+// Checks that a `const fn` can branch on a const parameter and fill an array of that length.
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+const fn parity_fill<const N: usize>() -> [u8; N] {
+    let value = if N % 2 == 0 { 0xEE } else { 0x0D };
+    [value; N]
+}
+
+const EMPTY: [u8; 0] = parity_fill::<0>();
+const ODD: [u8; 3] = parity_fill::<3>();
+const EVEN: [u8; 4] = parity_fill::<4>();
+
+fn main() {
+    assert_eq!(EMPTY, []);
+    assert_eq!(ODD, [0x0D; 3]);
+    assert_eq!(EVEN, [0xEE; 4]);
+    assert_eq!(parity_fill::<1>(), [0x0D]);
+    assert_eq!(parity_fill::<2>(), [0xEE, 0xEE]);
+}
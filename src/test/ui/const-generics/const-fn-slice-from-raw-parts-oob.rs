@@ -0,0 +1,19 @@
+// Check that a slice created by `slice::from_raw_parts` during const-eval, with a length that
+// is a const parameter, is checked against the bounds of the allocation it points into.
+// ignore-debug: the debug assertions in `slice::from_raw_parts` reject overlong slices first
+#![feature(min_const_generics, const_slice_from_raw_parts)]
+
+use std::slice;
+
+const fn prefix<const N: usize>(data: &'static [u16; 4]) -> &'static [u16] {
+    unsafe { slice::from_raw_parts(data as *const [u16; 4] as *const u16, N) }
+}
+
+const DATA: &[u16; 4] = &[1, 2, 3, 4];
+const ALL: &[u16] = prefix::<4>(DATA);
+const TOO_LONG: &[u16] = prefix::<5>(DATA);
+//~^ ERROR it is undefined behavior to use this value
+const MUCH_TOO_LONG: &[u16] = prefix::<{ usize::MAX / 2 }>(DATA);
+//~^ ERROR it is undefined behavior to use this value
+
+fn main() {}
@@ -0,0 +1,27 @@
+// Check that `slice::from_raw_parts` can be const-evaluated with a length that is a const
+// parameter.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+#![feature(const_slice_from_raw_parts)]
+
+use std::slice;
+
+const fn prefix<const N: usize>(data: &'static [u16; 4]) -> &'static [u16] {
+    unsafe { slice::from_raw_parts(data as *const [u16; 4] as *const u16, N) }
+}
+
+const DATA: &[u16; 4] = &[1, 2, 3, 4];
+const EMPTY: &[u16] = prefix::<0>(DATA);
+const TWO: &[u16] = prefix::<2>(DATA);
+const ALL: &[u16] = prefix::<4>(DATA);
+
+fn main() {
+    assert_eq!(EMPTY, &[]);
+    assert_eq!(TWO, &[1, 2]);
+    assert_eq!(ALL, &[1, 2, 3, 4]);
+    assert_eq!(prefix::<3>(DATA), &[1, 2, 3]);
+}
@@ -0,0 +1,18 @@
+// Check that slicing a `&'static str` in the middle of a multibyte char is caught during
+// const-eval, and that the backtrace names the offending byte index.
+#![feature(min_const_generics, const_panic)]
+#![feature(const_slice_from_raw_parts, const_str_from_utf8_unchecked)]
+#![crate_type = "lib"]
+
+const fn prefix<const N: usize>(s: &'static str) -> &'static str {
+    let bytes = s.as_bytes();
+    assert!(N <= bytes.len(), "byte index is out of bounds");
+    if N < bytes.len() && bytes[N] & 0xC0 == 0x80 {
+        panic!("byte index is not a char boundary"); //~ ERROR any use of this value
+    }
+    unsafe { std::str::from_utf8_unchecked(std::slice::from_raw_parts(bytes.as_ptr(), N)) }
+}
+
+const OK: &str = prefix::<1>("aéb");
+
+const MID_CHAR: &str = prefix::<2>("aéb");
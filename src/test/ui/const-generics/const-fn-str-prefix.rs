@@ -0,0 +1,28 @@
+// run-pass
+// Check that a `const fn` can slice a `&'static str` to a byte length given by a const parameter.
+#![feature(min_const_generics, const_panic)]
+#![feature(const_slice_from_raw_parts, const_str_from_utf8_unchecked)]
+
+const fn prefix<const N: usize>(s: &'static str) -> &'static str {
+    let bytes = s.as_bytes();
+    assert!(N <= bytes.len(), "byte index is out of bounds");
+    // Continuation bytes of a multibyte char have the form `0b10xx_xxxx`.
+    if N < bytes.len() && bytes[N] & 0xC0 == 0x80 {
+        panic!("byte index is not a char boundary");
+    }
+    // SAFETY: `N` is in bounds and on a char boundary, so the prefix is valid UTF-8.
+    unsafe { std::str::from_utf8_unchecked(std::slice::from_raw_parts(bytes.as_ptr(), N)) }
+}
+
+const HELLO: &str = prefix::<5>("hello, world");
+const EMPTY: &str = prefix::<0>("hello");
+const WHOLE: &str = prefix::<5>("hello");
+const MULTIBYTE: &str = prefix::<3>("aéb");
+
+fn main() {
+    assert_eq!(HELLO, "hello");
+    assert_eq!(EMPTY, "");
+    assert_eq!(WHOLE, "hello");
+    assert_eq!(MULTIBYTE, "aé");
+    assert_eq!(prefix::<4>("déjà"), "déj");
+}
@@ -0,0 +1,30 @@
+// Check that `ptr::write_bytes` can be const-evaluated with a count that is a const parameter.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+#![feature(const_ptr_write, const_mut_refs)]
+
+use std::ptr;
+
+// Fills the first `N` elements of a four-element buffer.
+const fn filled<const N: usize>(val: u8) -> [u16; 4] {
+    let mut buf = [0x1111; 4];
+    unsafe {
+        ptr::write_bytes(&mut buf as *mut [u16; 4] as *mut u16, val, N);
+    }
+    buf
+}
+
+const NONE: [u16; 4] = filled::<0>(0xff);
+const TWO: [u16; 4] = filled::<2>(0xab);
+const ALL: [u16; 4] = filled::<4>(0);
+
+fn main() {
+    assert_eq!(NONE, [0x1111; 4]);
+    assert_eq!(TWO, [0xabab, 0xabab, 0x1111, 0x1111]);
+    assert_eq!(ALL, [0; 4]);
+    assert_eq!(filled::<3>(0x01), [0x0101, 0x0101, 0x0101, 0x1111]);
+}
@@ -0,0 +1,16 @@
+// only-64bit
+// compile-flags: -Zconst-generic-overflow=error
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+fn f<const N: usize>() -> usize
+where
+    [u8; N * 2]: Sized,
+    //~^ ERROR evaluation of constant value failed
+{
+    N
+}
+
+fn main() {
+    f::<{ usize::MAX / 2 + 3 }>();
+}
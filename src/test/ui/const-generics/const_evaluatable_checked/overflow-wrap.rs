@@ -0,0 +1,18 @@
+// Check that `-Zconst-generic-overflow=wrap` makes overflow in const arguments wrap
+// around with a warning instead of being an error.
+// build-pass
+// only-64bit
+// compile-flags: -Zconst-generic-overflow=wrap
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+fn f<const N: usize>() -> usize
+where
+    [u8; N * 2]: Sized,
+{
+    N
+}
+
+fn main() {
+    f::<{ usize::MAX / 2 + 3 }>();
+}
@@ -0,0 +1,15 @@
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+fn bits<const N: usize>() -> u64
+where
+    [u8; N - 1]: Sized,
+    [u8; 64 - N]: Sized,
+    //~^ ERROR evaluation of constant value failed
+{
+    u64::MAX >> (64 - N)
+}
+
+fn main() {
+    bits::<100>();
+}
@@ -0,0 +1,19 @@
+// run-pass
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+// `1 <= N <= 64`, expressed as two expressions which only evaluate successfully
+// when the respective bound holds.
+fn bits<const N: usize>() -> u64
+where
+    [u8; N - 1]: Sized,
+    [u8; 64 - N]: Sized,
+{
+    u64::MAX >> (64 - N)
+}
+
+fn main() {
+    assert_eq!(bits::<1>(), 1);
+    assert_eq!(bits::<8>(), 0xff);
+    assert_eq!(bits::<64>(), u64::MAX);
+}
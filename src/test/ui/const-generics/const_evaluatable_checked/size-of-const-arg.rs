@@ -0,0 +1,20 @@
+// run-pass
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+fn foo<const N: usize>() -> usize {
+    N
+}
+
+fn size<T>() -> usize
+where
+    [u8; std::mem::size_of::<T>()]: Sized,
+{
+    foo::<{ std::mem::size_of::<T>() }>()
+}
+
+fn main() {
+    assert_eq!(size::<u64>(), 8);
+    assert_eq!(size::<u64>(), foo::<{ std::mem::size_of::<u64>() }>());
+    assert_eq!(size::<[u64; 3]>(), 24);
+}
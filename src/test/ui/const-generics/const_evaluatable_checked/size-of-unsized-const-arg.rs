@@ -0,0 +1,15 @@
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+fn foo<const N: usize>() -> usize {
+    N
+}
+
+fn size<T: ?Sized>() -> usize {
+    foo::<{ std::mem::size_of::<T>() }>()
+    //~^ ERROR the size for values of type `T` cannot be known at compilation time
+}
+
+fn main() {
+    size::<str>();
+}
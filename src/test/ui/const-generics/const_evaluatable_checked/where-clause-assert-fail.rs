@@ -0,0 +1,23 @@
+// Checks that a call whose const arguments violate a where clause bound is rejected, pointing
+// at the bound.
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+enum Assert<const CHECK: bool> {}
+
+trait IsTrue {}
+
+impl IsTrue for Assert<true> {}
+
+fn positive<const N: usize>() -> usize
+where
+    Assert<{ N > 0 }>: IsTrue,
+{
+    N
+}
+
+fn main() {
+    positive::<1>();
+    positive::<0>();
+    //~^ ERROR mismatched types
+}
@@ -0,0 +1,32 @@
+// run-pass
+// Checks that bounds on const parameters expressed through where clauses hold for arguments
+// that satisfy them.
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+enum Assert<const CHECK: bool> {}
+
+trait IsTrue {}
+
+impl IsTrue for Assert<true> {}
+
+fn positive<const N: usize>() -> usize
+where
+    Assert<{ N > 0 }>: IsTrue,
+{
+    N
+}
+
+fn ordered<const N: usize, const M: usize>() -> usize
+where
+    Assert<{ N < M }>: IsTrue,
+{
+    M - N
+}
+
+fn main() {
+    assert_eq!(positive::<1>(), 1);
+    assert_eq!(positive::<42>(), 42);
+    assert_eq!(ordered::<3, 7>(), 4);
+    assert_eq!(ordered::<0, 1>(), 1);
+}
@@ -0,0 +1,14 @@
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+fn f<const N: usize>() -> usize
+where
+    [u8; N - 2]: Sized,
+    //~^ ERROR evaluation of constant value failed
+{
+    N
+}
+
+fn main() {
+    f::<1>();
+}
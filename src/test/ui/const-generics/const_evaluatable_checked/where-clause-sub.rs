@@ -0,0 +1,16 @@
+// run-pass
+#![feature(const_generics, const_evaluatable_checked)]
+#![allow(incomplete_features)]
+
+fn f<const N: usize>() -> [u8; N - 2]
+where
+    [u8; N - 2]: Sized,
+{
+    [0; N - 2]
+}
+
+fn main() {
+    assert_eq!(f::<2>(), []);
+    assert_eq!(f::<3>(), [0]);
+    assert_eq!(f::<7>().len(), 5);
+}
@@ -0,0 +1,7 @@
+#![feature(const_generics, const_generics_defaults)]
+#![allow(incomplete_features)]
+
+struct Foo<const N: usize = M, const M: usize = 10>([u8; N], [u8; M]);
+//~^ ERROR const parameters with a default cannot use forward declared identifiers
+
+fn main() {}
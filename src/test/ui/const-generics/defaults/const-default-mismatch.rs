@@ -0,0 +1,15 @@
+// Checks that const arguments equal to their parameter's default are left out of type mismatch
+// errors, like type arguments equal to their default.
+#![feature(const_generics, const_generics_defaults)]
+#![allow(incomplete_features)]
+
+pub struct Example<T, const N: usize = 13>(pub [T; N]);
+
+pub fn make() -> Example<u16> {
+    loop {}
+}
+
+fn main() {
+    let _: Example<u8> = make(); //~ ERROR mismatched types
+    let _: Example<u8, 13> = make(); //~ ERROR mismatched types
+}
@@ -0,0 +1,13 @@
+#![feature(const_generics, const_generics_defaults)]
+#![allow(incomplete_features)]
+
+struct A<const N: usize = 3, const M: usize> {
+    //~^ ERROR const parameters with a default must be trailing
+    arr: [u8; N],
+    other: [u8; M],
+}
+
+fn foo<const N: usize = 3, T>() {}
+//~^ ERROR const parameters with a default must be trailing
+
+fn main() {}
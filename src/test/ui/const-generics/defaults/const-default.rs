@@ -0,0 +1,33 @@
+// run-pass
+// Checks that const parameter defaults are used when the argument is omitted.
+#![feature(const_generics, const_generics_defaults)]
+#![allow(incomplete_features)]
+
+struct Buffer<const N: usize = 16> {
+    data: [u8; N],
+}
+
+impl<const N: usize> Buffer<N> {
+    fn new() -> Self {
+        Buffer { data: [0; N] }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+fn make<const N: usize = 4>() -> [u8; N] {
+    [0; N]
+}
+
+fn main() {
+    let default: Buffer = Buffer::new();
+    assert_eq!(default.len(), 16);
+    let explicit: Buffer<3> = Buffer::new();
+    assert_eq!(explicit.len(), 3);
+
+    assert_eq!(make::<2>().len(), 2);
+    let inferred: [u8; 7] = make();
+    assert_eq!(inferred.len(), 7);
+}
@@ -0,0 +1,32 @@
+// run-pass
+// Checks defaulted type and const parameters in the same list, and const parameter defaults
+// that refer to earlier const parameters.
+#![feature(const_generics, const_generics_defaults)]
+#![allow(incomplete_features)]
+
+struct Mixed<T = u32, const N: usize = 3> {
+    arr: [T; N],
+}
+
+struct Square<const N: usize, const M: usize = N> {
+    rows: [[u8; M]; N],
+}
+
+struct Braced<const N: usize, const M: usize = { N }>([u8; M]);
+
+fn main() {
+    let m: Mixed = Mixed { arr: [1, 2, 3] };
+    let _: [u32; 3] = m.arr;
+    let m: Mixed<u8> = Mixed { arr: [1, 2, 3] };
+    let _: [u8; 3] = m.arr;
+    let m: Mixed<u8, 1> = Mixed { arr: [1] };
+    let _: [u8; 1] = m.arr;
+
+    let s: Square<2> = Square { rows: [[0; 2]; 2] };
+    assert_eq!(s.rows.len(), s.rows[0].len());
+    let s: Square<2, 4> = Square { rows: [[0; 4]; 2] };
+    assert_eq!(s.rows[0].len(), 4);
+
+    let b: Braced<5> = Braced([0; 5]);
+    assert_eq!(b.0.len(), 5);
+}
@@ -0,0 +1,37 @@
+// Check that a `Deref` impl on a type with a const parameter can unsize its
+// array field and be used for deref coercions.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::ops::Deref;
+
+struct Inline<const N: usize> {
+    data: [u8; N],
+}
+
+impl<const N: usize> Deref for Inline<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+fn len(slice: &[u8]) -> usize {
+    slice.len()
+}
+
+fn main() {
+    let inline = Inline { data: [1, 2, 3, 4] };
+    assert_eq!(len(&inline), 4);
+    assert_eq!(inline.len(), 4);
+    assert_eq!(inline[2], 3);
+    assert_eq!(inline.iter().sum::<u8>(), 10);
+
+    let empty: Inline<0> = Inline { data: [] };
+    assert!(empty.is_empty());
+}
@@ -0,0 +1,20 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+#[derive(Debug)]
+struct Buf<const N: usize>([u8; N]);
+
+#[derive(Debug)]
+struct Named<const N: usize> {
+    data: [u8; N],
+}
+
+fn main() {
+    assert_eq!(format!("{:?}", Buf([1, 2, 3])), "Buf([1, 2, 3])");
+    assert_eq!(format!("{:?}", Buf([])), "Buf([])");
+    assert_eq!(format!("{:?}", Named { data: [4, 5] }), "Named { data: [4, 5] }");
+}
@@ -0,0 +1,21 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::cmp::Ordering;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct Buf<const N: usize>([u8; N]);
+
+fn main() {
+    assert!(Buf([1, 2, 3, 4]) < Buf([1, 2, 4, 0]));
+    assert!(Buf([2, 0, 0, 0]) > Buf([1, 9, 9, 9]));
+    assert_eq!(Buf([1, 2, 3, 4]).cmp(&Buf([1, 2, 3, 4])), Ordering::Equal);
+    assert_eq!(Buf([5, 5, 5, 5]).max(Buf([5, 5, 6, 0])), Buf([5, 5, 6, 0]));
+
+    assert_eq!(Buf([]).cmp(&Buf([])), Ordering::Equal);
+    assert_eq!(Buf([]).partial_cmp(&Buf([])), Some(Ordering::Equal));
+}
@@ -0,0 +1,40 @@
+// Check that drop glue for a type with a const parameter calls the `Drop` impl
+// for the right value of the parameter, including when dropping through a vtable.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::cell::RefCell;
+
+thread_local! {
+    static DROPPED: RefCell<Vec<usize>> = RefCell::new(Vec::new());
+}
+
+trait Tr {}
+
+struct Buf<const N: usize>([u8; N]);
+
+impl<const N: usize> Tr for Buf<N> {}
+
+impl<const N: usize> Drop for Buf<N> {
+    fn drop(&mut self) {
+        DROPPED.with(|d| d.borrow_mut().push(N + self.0.len()));
+    }
+}
+
+fn take() -> Vec<usize> {
+    DROPPED.with(|d| std::mem::take(&mut *d.borrow_mut()))
+}
+
+fn main() {
+    drop(Buf([0; 3]));
+    drop(Buf([0; 5]));
+    assert_eq!(take(), [6, 10]);
+
+    let objs: Vec<Box<dyn Tr>> = vec![Box::new(Buf([0; 5])), Box::new(Buf([0; 3]))];
+    drop(objs);
+    assert_eq!(take(), [10, 6]);
+}
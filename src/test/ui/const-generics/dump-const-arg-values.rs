@@ -0,0 +1,19 @@
+// build-pass
+// compile-flags: -Z dump-const-arg-values
+// Checks that `-Z dump-const-arg-values` prints the value of every const argument of the
+// monomorphized functions.
+#![feature(min_const_generics)]
+
+fn foo<const N: usize>() -> usize {
+    N
+}
+
+fn bar<const B: bool, const C: char>() -> Option<char> {
+    if B { Some(C) } else { None }
+}
+
+fn main() {
+    foo::<18>();
+    foo::<3>();
+    bar::<true, 'x'>();
+}
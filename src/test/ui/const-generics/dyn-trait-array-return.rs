@@ -0,0 +1,37 @@
+// Check that methods returning arrays whose length is a const parameter of the trait
+// can be called through a trait object.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+trait T<const N: usize> {
+    fn get(&self) -> [u8; N];
+}
+
+struct Counter(u8);
+
+impl<const N: usize> T<N> for Counter {
+    fn get(&self) -> [u8; N] {
+        let mut arr = [0; N];
+        for (i, x) in arr.iter_mut().enumerate() {
+            *x = self.0 + i as u8;
+        }
+        arr
+    }
+}
+
+fn call<const N: usize>(obj: &dyn T<N>) -> [u8; N] {
+    obj.get()
+}
+
+fn main() {
+    let counter = Counter(7);
+    let obj: &dyn T<3> = &counter;
+    assert_eq!(obj.get(), [7, 8, 9]);
+    assert_eq!(call::<3>(&counter), [7, 8, 9]);
+    assert_eq!(call::<16>(&counter)[15], 22);
+    assert_eq!(call::<0>(&counter), []);
+}
@@ -0,0 +1,34 @@
+// Check that the vtable of a trait object created during const-eval contains
+// method instances with the trait's const parameter substituted.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+trait T<const N: usize> {
+    fn n(&self) -> usize;
+
+    fn default_n(&self) -> usize {
+        N
+    }
+}
+
+struct S;
+
+impl<const N: usize> T<N> for S {
+    fn n(&self) -> usize {
+        N
+    }
+}
+
+const THREE: &dyn T<3> = &S;
+const FIVE: &dyn T<5> = &S;
+
+fn main() {
+    assert_eq!(THREE.n(), 3);
+    assert_eq!(THREE.default_n(), 3);
+    assert_eq!(FIVE.n(), 5);
+    assert_eq!(FIVE.default_n(), 5);
+}
@@ -0,0 +1,26 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+#[derive(Debug, PartialEq)]
+struct Buf<const N: usize> {
+    data: [u8; N],
+}
+
+impl<const N: usize> From<[u8; N]> for Buf<N> {
+    fn from(data: [u8; N]) -> Self {
+        Buf { data }
+    }
+}
+
+fn main() {
+    let buf: Buf<3> = [1, 2, 3].into();
+    assert_eq!(buf.data, [1, 2, 3]);
+    assert_eq!(Buf::from([4, 5]), Buf { data: [4, 5] });
+
+    let empty: Buf<0> = [].into();
+    assert_eq!(empty.data, []);
+}
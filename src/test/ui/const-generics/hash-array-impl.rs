@@ -0,0 +1,29 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+struct Buf<const N: usize>([u8; N]);
+
+impl<const N: usize> Hash for Buf<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    assert_eq!(hash(&Buf([1, 2, 3, 4])), hash(&Buf([1, 2, 3, 4])));
+    assert_ne!(hash(&Buf([1, 2, 3, 4])), hash(&Buf([4, 3, 2, 1])));
+    assert_eq!(hash(&Buf([])), hash(&Buf([])));
+}
@@ -0,0 +1,25 @@
+// Checks that a const generic `Default` impl initializing an array is selected for
+// concrete lengths.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+#[derive(Debug, PartialEq)]
+struct Buf<const N: usize>([u8; N]);
+
+impl<const N: usize> Default for Buf<N> {
+    fn default() -> Self {
+        Buf([0; N])
+    }
+}
+
+fn main() {
+    let buf = Buf::<4>::default();
+    assert_eq!(buf.0, [0; 4]);
+
+    let buf: Buf<0> = Default::default();
+    assert_eq!(buf, Buf([]));
+}
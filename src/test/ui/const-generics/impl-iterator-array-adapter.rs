@@ -0,0 +1,19 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+#![feature(array_value_iter)]
+
+use std::array::IntoIter;
+
+fn incremented<const N: usize>(arr: [u8; N]) -> impl Iterator<Item = u8> {
+    IntoIter::new(arr).map(|x| x + 1)
+}
+
+fn main() {
+    assert_eq!(incremented([1, 2, 3]).collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(incremented([0; 16]).count(), 16);
+    assert_eq!(incremented([]).next(), None);
+}
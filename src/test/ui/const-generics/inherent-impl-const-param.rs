@@ -0,0 +1,38 @@
+// run-pass
+// revisions: full min
+// Checks that const parameters declared on an inherent impl can be used in its items.
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+struct Foo<const N: usize>;
+
+impl<const N: usize> Foo<N> {
+    const LEN: usize = N;
+
+    fn get(&self) -> usize {
+        N
+    }
+
+    fn zeroes() -> [u8; N] {
+        [0; N]
+    }
+
+    fn filled(&self, value: u8) -> [u8; N] {
+        let mut arr = [0; N];
+        for elem in arr.iter_mut() {
+            *elem = value;
+        }
+        arr
+    }
+}
+
+fn main() {
+    let foo = Foo::<3>;
+    assert_eq!(foo.get(), 3);
+    assert_eq!(Foo::<5>.get(), 5);
+    assert_eq!(Foo::<3>::LEN, 3);
+    let zeroes: [u8; 4] = Foo::<4>::zeroes();
+    assert_eq!(zeroes, [0; 4]);
+    assert_eq!(foo.filled(7), [7, 7, 7]);
+}
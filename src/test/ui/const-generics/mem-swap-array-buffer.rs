@@ -0,0 +1,45 @@
+// Check that `mem::swap` exchanges values of types containing arrays whose length is a const
+// parameter.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::mem;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Buf<const N: usize> {
+    len: usize,
+    data: [u8; N],
+}
+
+impl<const N: usize> Buf<N> {
+    fn filled(byte: u8) -> Self {
+        Buf { len: N, data: [byte; N] }
+    }
+}
+
+fn swapped<const N: usize>(a: u8, b: u8) {
+    let mut x = Buf::<N>::filled(a);
+    let mut y = Buf::<N>::filled(b);
+    mem::swap(&mut x, &mut y);
+    assert_eq!(x, Buf::filled(b));
+    assert_eq!(y, Buf::filled(a));
+}
+
+fn main() {
+    let mut a = Buf { len: 4, data: [1, 2, 3, 4] };
+    let mut b = Buf { len: 3, data: [5, 6, 7, 8] };
+    mem::swap(&mut a, &mut b);
+    assert_eq!(a, Buf { len: 3, data: [5, 6, 7, 8] });
+    assert_eq!(b, Buf { len: 4, data: [1, 2, 3, 4] });
+
+    swapped::<0>(1, 2);
+    swapped::<1>(3, 4);
+    swapped::<33>(5, 6);
+    swapped::<1000>(7, 8);
+
+    assert_eq!(mem::size_of::<Buf<0>>(), mem::size_of::<usize>());
+}
@@ -27,12 +27,12 @@ fn main() {
   assert!(get_flag::<true, 'c'>().is_some());
   assert!(get_flag::<false, 'x'>().is_none());
   get_flag::<false, 0xFF>();
-  //~^ ERROR mismatched types
+  //~^ ERROR expected const argument of type
   get_flag::<7, 'c'>();
-  //~^ ERROR mismatched types
+  //~^ ERROR expected const argument of type
   get_flag::<42, 0x5ad>();
-  //~^ ERROR mismatched types
-  //~| ERROR mismatched types
+  //~^ ERROR expected const argument of type
+  //~| ERROR expected const argument of type
 
 
   get_flag::<false, { unsafe { char_raw.character } }>();
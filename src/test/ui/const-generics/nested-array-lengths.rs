@@ -0,0 +1,29 @@
+// Check that both dimensions of a nested array are monomorphized.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::mem::size_of_val;
+
+fn f<const M: usize, const N: usize>() -> [[u8; M]; N] {
+    [[0; M]; N]
+}
+
+fn main() {
+    let arr = f::<2, 3>();
+    assert_eq!(arr.len(), 3);
+    assert_eq!(arr[0].len(), 2);
+    assert_eq!(size_of_val(&arr), 6);
+    assert_eq!(arr, [[0; 2]; 3]);
+
+    let arr = f::<0, 3>();
+    assert_eq!(arr.len(), 3);
+    assert_eq!(arr[2].len(), 0);
+
+    let arr = f::<2, 0>();
+    assert_eq!(arr.len(), 0);
+    assert_eq!(size_of_val(&arr), 0);
+}
@@ -0,0 +1,20 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+struct Buf<const N: usize>([u8; N]);
+
+impl<const N: usize> PartialEq for Buf<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+fn main() {
+    assert!(Buf([1, 2, 3, 4]) == Buf([1, 2, 3, 4]));
+    assert!(Buf([1, 2, 3, 4]) != Buf([1, 2, 3, 5]));
+    assert!(Buf([]) == Buf([]));
+}
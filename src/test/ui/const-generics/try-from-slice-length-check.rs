@@ -0,0 +1,35 @@
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::convert::TryFrom;
+
+#[derive(Debug, PartialEq)]
+struct Buf<const N: usize>([u8; N]);
+
+#[derive(Debug, PartialEq)]
+struct WrongLength(usize);
+
+impl<const N: usize> TryFrom<&[u8]> for Buf<N> {
+    type Error = WrongLength;
+
+    fn try_from(slice: &[u8]) -> Result<Self, WrongLength> {
+        if slice.len() != N {
+            return Err(WrongLength(slice.len()));
+        }
+        let mut data = [0; N];
+        data.copy_from_slice(slice);
+        Ok(Buf(data))
+    }
+}
+
+fn main() {
+    let bytes = [1, 2, 3, 4, 5];
+    assert_eq!(Buf::<4>::try_from(&bytes[..4]), Ok(Buf([1, 2, 3, 4])));
+    assert_eq!(Buf::<4>::try_from(&bytes[..]), Err(WrongLength(5)));
+    assert_eq!(Buf::<4>::try_from(&bytes[..3]), Err(WrongLength(3)));
+    assert_eq!(Buf::<0>::try_from(&bytes[..0]), Ok(Buf([])));
+}
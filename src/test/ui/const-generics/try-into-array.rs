@@ -0,0 +1,32 @@
+// run-pass
+// revisions: full min
+// Checks that a const parameter flows into the length of an array converted from a slice.
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::array::TryFromSliceError;
+use std::convert::TryInto;
+
+fn head<const N: usize>(slice: &[u8]) -> Result<[u8; N], TryFromSliceError> {
+    let arr: [u8; N] = slice.try_into()?;
+    Ok(arr)
+}
+
+fn head_ref<const N: usize>(slice: &[u8]) -> Option<&[u8; N]> {
+    slice.try_into().ok()
+}
+
+fn main() {
+    let bytes = [1, 2, 3, 4];
+
+    assert_eq!(head::<4>(&bytes), Ok([1, 2, 3, 4]));
+    assert_eq!(head::<2>(&bytes[..2]), Ok([1, 2]));
+    assert_eq!(head::<0>(&[]), Ok([]));
+    assert!(head::<3>(&bytes).is_err());
+    assert!(head::<8>(&bytes).is_err());
+    assert!(head::<1>(&[]).is_err());
+
+    assert_eq!(head_ref::<4>(&bytes), Some(&[1, 2, 3, 4]));
+    assert_eq!(head_ref::<5>(&bytes), None);
+}
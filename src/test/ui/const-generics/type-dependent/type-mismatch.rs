@@ -10,5 +10,5 @@ fn method<const N: u8>(&self) -> u8 { N }
 }
 fn main() {
     assert_eq!(R.method::<1u16>(), 1);
-    //~^ ERROR mismatched types
+    //~^ ERROR expected const argument of type
 }
@@ -0,0 +1,49 @@
+// Check that impls of a user-defined trait for a type with a const parameter can
+// iterate over an array of that length.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+trait Serializer {
+    fn write_u8(&mut self, value: u8);
+    fn write_len(&mut self, len: usize);
+}
+
+impl Serializer for Vec<u8> {
+    fn write_u8(&mut self, value: u8) {
+        self.push(value);
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.push(len as u8);
+    }
+}
+
+trait MySerialize {
+    fn serialize<S: Serializer>(&self, serializer: &mut S);
+}
+
+struct Buf<const N: usize>([u8; N]);
+
+impl<const N: usize> MySerialize for Buf<N> {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) {
+        serializer.write_len(N);
+        for &byte in self.0.iter() {
+            serializer.write_u8(byte);
+        }
+    }
+}
+
+fn to_bytes<T: MySerialize>(value: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    value.serialize(&mut out);
+    out
+}
+
+fn main() {
+    assert_eq!(to_bytes(&Buf([9, 8, 7, 6])), [4, 9, 8, 7, 6]);
+    assert_eq!(to_bytes(&Buf([])), [0]);
+}
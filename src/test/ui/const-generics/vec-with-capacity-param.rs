@@ -0,0 +1,30 @@
+// Check that a const parameter can be passed as a runtime value, here to `Vec::with_capacity`.
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+fn buffer<const N: usize>() -> Vec<u8> {
+    Vec::with_capacity(N)
+}
+
+fn filled<T: Clone, const N: usize>(value: T) -> Vec<T> {
+    let mut v = Vec::with_capacity(N);
+    v.resize(N, value);
+    v
+}
+
+fn main() {
+    assert!(buffer::<0>().is_empty());
+    assert!(buffer::<1>().capacity() >= 1);
+    assert!(buffer::<16>().capacity() >= 16);
+    assert!(buffer::<4096>().capacity() >= 4096);
+
+    assert_eq!(filled::<String, 0>(String::from("x")), Vec::<String>::new());
+    assert_eq!(filled::<u16, 3>(7), [7, 7, 7]);
+    let v = filled::<(), 1000>(());
+    assert_eq!(v.len(), 1000);
+    assert!(v.capacity() >= 1000);
+}
@@ -0,0 +1,18 @@
+// Check that an error while evaluating a const argument in a where clause points at
+// the const argument, not at the whole bound.
+#![feature(const_generics)]
+#![allow(incomplete_features)]
+
+const ONE: usize = 1;
+const TWO: usize = 2;
+
+trait Trait<const N: usize> {}
+
+fn f<T>()
+where
+    T: Trait<{ ONE - TWO }>,
+    //~^ ERROR evaluation of constant value failed
+{
+}
+
+fn main() {}
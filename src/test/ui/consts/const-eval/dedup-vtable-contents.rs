@@ -0,0 +1,24 @@
+// run-pass
+// Check that trait objects whose trait refs only differ before region erasure share a
+// vtable during const-eval, and that the shared vtable still dispatches correctly.
+
+trait Tr<T> {
+    fn get(&self) -> usize;
+}
+
+impl<T> Tr<T> for u8 {
+    fn get(&self) -> usize {
+        *self as usize
+    }
+}
+
+const HIGHER_RANKED: &dyn for<'a> Tr<&'a u8> = &1u8;
+const STATIC: &dyn Tr<&'static u8> = &2u8;
+const BOTH: (&dyn for<'a> Tr<&'a u8>, &dyn Tr<&'static u8>) = (&3u8, &4u8);
+
+fn main() {
+    assert_eq!(HIGHER_RANKED.get(), 1);
+    assert_eq!(STATIC.get(), 2);
+    assert_eq!(BOTH.0.get(), 3);
+    assert_eq!(BOTH.1.get(), 4);
+}
@@ -0,0 +1,16 @@
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {
+    fn foo(&self) {}
+}
+
+impl Tr for u8 {}
+
+#[rustc_dump_vtable(cache_disabled)]
+pub const U8_TR: &dyn Tr = &0u8;
+//~^ ERROR cached vtables shared: true, uncached vtables shared: false, cache kept: true
+
+#[rustc_dump_vtable(cache_disabled)]
+pub const U8_SEND: &dyn Send = &0u8;
+//~^ ERROR cached vtables shared: true, uncached vtables shared: false, cache kept: true
@@ -0,0 +1,26 @@
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {
+    fn first(&self);
+    fn sized(&self) where Self: Sized {}
+    fn last(&self) {}
+}
+
+pub struct D(pub u16);
+
+impl Drop for D {
+    fn drop(&mut self) {}
+}
+
+impl Tr for D {
+    fn first(&self) {}
+}
+
+#[rustc_dump_vtable(dump)]
+pub const D_TR: &dyn Tr = &D(0);
+//~^ ERROR dump: drop: `drop_in_place::<D> - shim(Some(D))`, size: 2, align: 2, methods:
+
+#[rustc_dump_vtable(dump)]
+pub const U8_SEND: &dyn Send = &0u8;
+//~^ ERROR dump: drop: `drop_in_place::<u8> - shim(None)`, size: 1, align: 1, methods: []
@@ -0,0 +1,33 @@
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {
+    fn foo(&self);
+    fn bar(&self) {}
+}
+
+pub struct A;
+pub struct B(pub u32);
+
+impl Tr for A {
+    fn foo(&self) {}
+}
+
+impl Tr for B {
+    fn foo(&self) {}
+    fn bar(&self) {}
+}
+
+#[rustc_dump_vtable(diff)]
+pub const SAME: (&dyn Tr, &dyn Tr) = (&A, &A); //~ ERROR diff: no differences
+
+#[rustc_dump_vtable(diff)]
+pub const DIFFERENT: (&dyn Tr, &dyn Tr) = (&A, &B(0));
+//~^ ERROR diff: slot 0: drop
+//~| ERROR diff: slot 1: size: 0 != 4
+//~| ERROR diff: slot 2: align: 1 != 4
+//~| ERROR diff: slot 3: method 0
+//~| ERROR diff: slot 4: method 1
+
+#[rustc_dump_vtable(diff)]
+pub const NOT_A_TRAIT_OBJECT: &u8 = &0; //~ ERROR needs a constant that is a reference
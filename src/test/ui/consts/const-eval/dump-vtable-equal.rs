@@ -0,0 +1,31 @@
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {
+    fn foo(&self) {}
+}
+
+pub struct A;
+pub struct B(pub u32);
+
+impl Tr for A {}
+impl Tr for B {}
+
+#[rustc_dump_vtable(equal)]
+pub const SAME: (&dyn Tr, &dyn Tr) = (&A, &A);
+//~^ ERROR equal: pair: true
+//~| ERROR equal: uncached copy: true
+//~| ERROR equal: uncached copy: true
+
+#[rustc_dump_vtable(equal)]
+pub const DIFFERENT: (&dyn Tr, &dyn Tr) = (&A, &B(0));
+//~^ ERROR equal: pair: false
+//~| ERROR equal: uncached copy: true
+//~| ERROR equal: uncached copy: true
+
+// Different trait object types, but the same vtable contents.
+#[rustc_dump_vtable(equal)]
+pub const AUTO_TRAIT: (&dyn Tr, &(dyn Tr + Send)) = (&A, &A);
+//~^ ERROR equal: pair: true
+//~| ERROR equal: uncached copy: true
+//~| ERROR equal: uncached copy: true
@@ -0,0 +1,30 @@
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {
+    fn foo(&self) {}
+}
+
+pub struct A;
+
+impl Tr for A {}
+
+#[rustc_dump_vtable(fn_trait)]
+pub const FN: &dyn Fn() = &|| {};
+//~^ ERROR fn_trait: Some(Fn), with the cache disabled: Some(Fn)
+
+#[rustc_dump_vtable(fn_trait)]
+pub const FN_MUT: &dyn FnMut() = &|| {};
+//~^ ERROR fn_trait: Some(FnMut), with the cache disabled: Some(FnMut)
+
+#[rustc_dump_vtable(fn_trait)]
+pub const FN_ONCE: &dyn FnOnce() = &|| {};
+//~^ ERROR fn_trait: Some(FnOnce), with the cache disabled: Some(FnOnce)
+
+#[rustc_dump_vtable(fn_trait)]
+pub const NOT_FN: &dyn Tr = &A;
+//~^ ERROR fn_trait: None, with the cache disabled: None
+
+#[rustc_dump_vtable(fn_trait)]
+pub const NO_PRINCIPAL: &dyn Send = &A;
+//~^ ERROR fn_trait: None, with the cache disabled: None
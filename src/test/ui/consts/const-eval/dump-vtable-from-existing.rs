@@ -0,0 +1,24 @@
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {
+    fn foo(&self) {}
+}
+
+// Too small to hold a vtable header.
+pub struct Small(pub u32);
+// Large enough for a vtable header, but holding integers instead of a drop function.
+pub struct Large(pub [usize; 4]);
+
+impl Tr for Small {}
+impl Tr for Large {}
+
+#[rustc_dump_vtable(from_existing)]
+pub const SMALL: &dyn Tr = &Small(0);
+//~^ ERROR from_existing: vtable pointer accepted and reused by `get_vtable`
+//~| ERROR from_existing: data pointer rejected
+
+#[rustc_dump_vtable(from_existing)]
+pub const LARGE: &dyn Tr = &Large([0; 4]);
+//~^ ERROR from_existing: vtable pointer accepted and reused by `get_vtable`
+//~| ERROR from_existing: data pointer rejected
@@ -0,0 +1,21 @@
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {
+    fn first(&self);
+    fn sized(&self) where Self: Sized {}
+    fn last(&self) {}
+}
+
+pub struct A;
+
+impl Tr for A {
+    fn first(&self) {}
+}
+
+#[rustc_dump_vtable(layout)]
+pub const A_TR: &dyn Tr = &A;
+//~^ ERROR layout: [`<A as Tr>::first`, empty, `Tr::last`], reused: true
+
+#[rustc_dump_vtable(layout)]
+pub const A_SEND: &dyn Send = &A; //~ ERROR layout: [], reused: true
@@ -0,0 +1,25 @@
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {
+    fn first(&self);
+    fn sized(&self) where Self: Sized {}
+    fn last(&self) {}
+}
+
+pub struct A;
+
+impl Tr for A {
+    fn first(&self) {}
+}
+
+#[rustc_dump_vtable(method_at)]
+pub const A_TR: &dyn Tr = &A;
+//~^ ERROR method_at: 0: `<A as Tr>::first`
+//~| ERROR method_at: 1: empty
+//~| ERROR method_at: 2: `<A as Tr>::last`
+//~| ERROR method_at: 3: vtable method index 3 is out of range for a vtable with 3 methods
+
+#[rustc_dump_vtable(method_at)]
+pub const A_SEND: &dyn Send = &A;
+//~^ ERROR method_at: 0: vtable method index 0 is out of range for a vtable with 0 methods
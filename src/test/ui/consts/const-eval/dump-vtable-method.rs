@@ -0,0 +1,27 @@
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {
+    fn first(&self);
+    fn sized(&self) where Self: Sized {}
+    fn last(&self) {}
+}
+
+pub struct A;
+
+impl Tr for A {
+    fn first(&self) {}
+}
+
+#[rustc_dump_vtable(method = "first", method = "last", method = "sized", method = "missing")]
+pub const A_TR: &dyn Tr = &A;
+//~^ ERROR method: `<A as Tr>::first` is method 0
+//~| ERROR method: `<A as Tr>::last` is method 2
+//~| ERROR method: `sized` has no slot
+//~| ERROR method: `missing` has no slot
+
+#[rustc_dump_vtable(method = "first")]
+pub const A_SEND: &dyn Send = &A; //~ ERROR method: no principal trait
+
+#[rustc_dump_vtable(method)] //~ ERROR expected `method = "name"`
+pub const NO_NAME: &dyn Tr = &A;
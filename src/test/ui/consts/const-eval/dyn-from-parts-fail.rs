@@ -0,0 +1,25 @@
+// compile-flags: -Zvalidate-vtables
+// Check that trait objects transmuted from a data pointer and a vtable are rejected when the
+// data doesn't match the size and alignment the vtable records, or the vtable isn't one.
+
+use std::mem;
+
+trait Trait {}
+impl Trait for u32 {}
+impl Trait for u64 {}
+
+const U32_VTABLE: *const () =
+    unsafe { mem::transmute::<&dyn Trait, (*const (), *const ())>(&0u32).1 };
+const U64_VTABLE: *const () =
+    unsafe { mem::transmute::<&dyn Trait, (*const (), *const ())>(&0u64).1 };
+
+const UNALIGNED: &dyn Trait = unsafe { mem::transmute((&[0u8; 8], U32_VTABLE)) };
+//~^ ERROR it is undefined behavior to use this value
+
+const TOO_SMALL: &dyn Trait = unsafe { mem::transmute((&[0u64; 0], U64_VTABLE)) };
+//~^ ERROR it is undefined behavior to use this value
+
+const NOT_A_VTABLE: &dyn Trait = unsafe { mem::transmute((&0u32, &[0usize; 3])) };
+//~^ ERROR it is undefined behavior to use this value
+
+fn main() {}
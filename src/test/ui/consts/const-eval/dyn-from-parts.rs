@@ -0,0 +1,25 @@
+// run-pass
+// compile-flags: -Zvalidate-vtables
+// Check that a trait object transmuted from a data pointer and the vtable of another trait
+// object of the same type passes validation.
+
+use std::mem;
+
+trait Trait {
+    fn get(&self) -> u64;
+}
+
+impl Trait for u32 {
+    fn get(&self) -> u64 {
+        *self as u64
+    }
+}
+
+const U32_VTABLE: *const () =
+    unsafe { mem::transmute::<&dyn Trait, (*const (), *const ())>(&0u32).1 };
+
+const OBJ: &dyn Trait = unsafe { mem::transmute((&7u32, U32_VTABLE)) };
+
+fn main() {
+    assert_eq!(OBJ.get(), 7);
+}
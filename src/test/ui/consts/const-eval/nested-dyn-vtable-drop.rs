@@ -0,0 +1,68 @@
+// run-pass
+// Check that const-eval finds the drop function of vtables for trait objects of subtraits,
+// including trait objects nested in the dynamic type of another trait object: validating the
+// final value of these constants reads the drop slot of every vtable in it.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+trait Super {
+    fn depth(&self) -> u8;
+}
+
+trait Sub: Super {
+    fn value(&self) -> u8;
+}
+
+struct Leaf(u8);
+
+impl Drop for Leaf {
+    fn drop(&mut self) {
+        DROPS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl Super for Leaf {
+    fn depth(&self) -> u8 {
+        0
+    }
+}
+
+impl Sub for Leaf {
+    fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+struct Node<'a>(&'a dyn Sub);
+
+impl Super for Node<'_> {
+    fn depth(&self) -> u8 {
+        self.0.depth() + 1
+    }
+}
+
+impl Sub for Node<'_> {
+    fn value(&self) -> u8 {
+        self.0.value()
+    }
+}
+
+const LEAF: &dyn Sub = &Leaf(7);
+const NODE: &dyn Sub = &Node(LEAF);
+const NESTED: &dyn Sub = &Node(NODE);
+const AS_SUPER: &dyn Super = &Node(NODE);
+
+fn main() {
+    assert_eq!((LEAF.depth(), LEAF.value()), (0, 7));
+    assert_eq!((NODE.depth(), NODE.value()), (1, 7));
+    assert_eq!((NESTED.depth(), NESTED.value()), (2, 7));
+    assert_eq!(AS_SUPER.depth(), 2);
+
+    // The vtables built at run time must agree with the ones built during const-eval about
+    // which drop function to call.
+    let boxed: Box<dyn Sub> = Box::new(Leaf(1));
+    drop(boxed);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,37 @@
+// run-pass
+// Check that a generic method appearing in the vtables of both a trait and its subtrait is
+// dispatched correctly from each vtable. During const-eval both vtables point at the same
+// fn-alloc for that method.
+
+trait Base {
+    fn value(&self) -> usize;
+}
+
+trait Sub: Base {
+    fn double(&self) -> usize {
+        self.value() * 2
+    }
+}
+
+struct Wrapper<T>(T);
+
+impl<T> Base for Wrapper<T> {
+    fn value(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+}
+
+impl<T> Sub for Wrapper<T> {}
+
+const BASE: &dyn Base = &Wrapper(0u32);
+const SUB: &dyn Sub = &Wrapper(0u32);
+const BOTH: (&dyn Base, &dyn Sub) = (&Wrapper(0u64), &Wrapper(0u64));
+
+fn main() {
+    assert_eq!(BASE.value(), 4);
+    assert_eq!(SUB.value(), 4);
+    assert_eq!(SUB.double(), 8);
+    assert_eq!(BOTH.0.value(), 8);
+    assert_eq!(BOTH.1.value(), 8);
+    assert_eq!(BOTH.1.double(), 16);
+}
@@ -0,0 +1,34 @@
+// run-pass
+// Check that unsizing a struct to one with a trait object tail during const-eval uses the
+// vtable of the struct's tail type, the same one as unsizing a value of that type directly.
+
+use std::mem;
+
+trait Tr {
+    fn get(&self) -> usize;
+}
+
+impl Tr for u8 {
+    fn get(&self) -> usize {
+        *self as usize
+    }
+}
+
+struct Wrapper<T: ?Sized>(u16, T);
+
+const WRAPPED: &Wrapper<dyn Tr> = &Wrapper(7, 3u8);
+const NESTED: &Wrapper<Wrapper<dyn Tr>> = &Wrapper(8, Wrapper(9, 4u8));
+const DIRECT: &dyn Tr = &3u8;
+
+fn main() {
+    assert_eq!(WRAPPED.0, 7);
+    assert_eq!(WRAPPED.1.get(), 3);
+    assert_eq!(WRAPPED.1.get(), DIRECT.get());
+    assert_eq!(mem::size_of_val(WRAPPED), mem::size_of::<Wrapper<u8>>());
+    assert_eq!(mem::size_of_val(&WRAPPED.1), mem::size_of_val(DIRECT));
+
+    assert_eq!(NESTED.0, 8);
+    assert_eq!((NESTED.1).0, 9);
+    assert_eq!((NESTED.1).1.get(), 4);
+    assert_eq!(mem::size_of_val(NESTED), mem::size_of::<Wrapper<Wrapper<u8>>>());
+}
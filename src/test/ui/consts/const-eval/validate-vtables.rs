@@ -0,0 +1,38 @@
+// compile-flags: -Zvalidate-vtables
+// ignore-tidy-linelength
+#![allow(const_err)]
+
+use std::mem;
+
+trait Trait {
+    fn method(&self) {}
+}
+impl Trait for u8 {}
+
+fn drop_u8(_: *mut u8) {}
+
+#[repr(C)]
+struct Vtable<M> {
+    drop: fn(*mut u8),
+    size: usize,
+    align: usize,
+    method: M,
+}
+
+// A vtable created by the compiler is well-formed.
+const REAL: &dyn Trait = &42u8;
+
+// Hand-built vtables are accepted as long as they look like a real one.
+const GOOD: &dyn Trait = unsafe { mem::transmute((&92u8, &Vtable { drop: drop_u8, size: 1, align: 1, method: <u8 as Trait>::method as fn(&u8) })) };
+const GOOD_EMPTY_SLOT: &dyn Trait = unsafe { mem::transmute((&92u8, &Vtable { drop: drop_u8, size: 1, align: 1, method: 0usize })) };
+
+const BAD_ALIGN: &dyn Trait = unsafe { mem::transmute((&92u8, &Vtable { drop: drop_u8, size: 1, align: 3, method: 0usize })) };
+//~^ ERROR it is undefined behavior to use this value
+const BAD_SIZE: &dyn Trait = unsafe { mem::transmute((&92u8, &Vtable { drop: drop_u8, size: 3, align: 2, method: 0usize })) };
+//~^ ERROR it is undefined behavior to use this value
+const BAD_METHOD_INT: &dyn Trait = unsafe { mem::transmute((&92u8, &Vtable { drop: drop_u8, size: 1, align: 1, method: 1usize })) };
+//~^ ERROR it is undefined behavior to use this value
+const BAD_METHOD_NOT_FN_PTR: &dyn Trait = unsafe { mem::transmute((&92u8, &Vtable { drop: drop_u8, size: 1, align: 1, method: &42u8 })) };
+//~^ ERROR it is undefined behavior to use this value
+
+fn main() {}
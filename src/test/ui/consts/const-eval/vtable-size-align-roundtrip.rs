@@ -0,0 +1,32 @@
+// run-pass
+// Size and alignment read back from a vtable during const-eval must agree with the layout
+// computed for the target, whatever its pointer width.
+
+#![feature(const_size_of_val, const_align_of_val)]
+
+use std::mem;
+
+trait Trait {}
+
+struct Wide(u64, u8);
+struct Ptrs(usize, *const u8, u16);
+struct Byte(u8);
+
+impl Trait for Wide {}
+impl Trait for Ptrs {}
+impl Trait for Byte {}
+
+const WIDE: &dyn Trait = &Wide(1, 2);
+const PTRS: &dyn Trait = &Ptrs(1, 0 as *const u8, 3);
+const BYTE: &dyn Trait = &Byte(4);
+
+const SIZES: [usize; 3] =
+    [mem::size_of_val(WIDE), mem::size_of_val(PTRS), mem::size_of_val(BYTE)];
+const ALIGNS: [usize; 3] =
+    [mem::align_of_val(WIDE), mem::align_of_val(PTRS), mem::align_of_val(BYTE)];
+
+fn main() {
+    assert_eq!(SIZES, [mem::size_of::<Wide>(), mem::size_of::<Ptrs>(), mem::size_of::<Byte>()]);
+    assert_eq!(ALIGNS, [mem::align_of::<Wide>(), mem::align_of::<Ptrs>(), mem::align_of::<Byte>()]);
+    assert_eq!(mem::size_of::<&dyn Trait>(), 2 * mem::size_of::<usize>());
+}
@@ -0,0 +1,15 @@
+// normalize-stderr-test "alloc\d+" -> "allocN"
+#![feature(const_raw_ptr_deref, const_mut_refs)]
+
+trait Tr {}
+impl Tr for u8 {}
+
+// Vtables are immutable once `get_vtable` has created them.
+const _: () = unsafe {
+    let obj: &dyn Tr = &0u8;
+    let [_, vtable]: [*mut usize; 2] = std::mem::transmute(obj);
+    *vtable = 0;
+    //~^ ERROR any use of this value will cause an error
+};
+
+fn main() {}
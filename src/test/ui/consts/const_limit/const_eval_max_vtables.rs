@@ -0,0 +1,14 @@
+// compile-flags: -Zconst-eval-max-vtables=2
+
+trait Tr {}
+
+impl Tr for u8 {}
+impl Tr for u16 {}
+impl Tr for u32 {}
+
+const _: [&dyn Tr; 3] = [&0u8, &0u16, &0u32];
+//~^ ERROR any use of this value will cause an error
+
+const _: [&dyn Tr; 4] = [&0u8, &1u8, &0u16, &1u16];
+
+fn main() {}
@@ -0,0 +1,14 @@
+// Check that vtables created with the vtable cache disabled count against
+// `-Zconst-eval-max-vtables`, even though they are not added to the cache.
+// compile-flags: -Zconst-eval-max-vtables=2
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+pub trait Tr {}
+
+impl Tr for u8 {}
+
+// Creates one cached vtable, then two uncached ones. The second uncached one is over the limit.
+#[rustc_dump_vtable(cache_disabled)]
+pub const U8_TR: &dyn Tr = &0u8;
+//~^ ERROR cache_disabled: reached the configured maximum number of vtables
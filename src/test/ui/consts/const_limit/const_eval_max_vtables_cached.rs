@@ -0,0 +1,25 @@
+// Check that reusing a vtable that was already created does not count against
+// `-Zconst-eval-max-vtables`.
+// check-pass
+// compile-flags: -Zconst-eval-max-vtables=1
+
+trait Tr {}
+
+impl Tr for u8 {}
+
+const _: [&dyn Tr; 3] = [&0u8, &1u8, &2u8];
+
+// Different trait object types with identical vtable contents share one vtable.
+const _: (&dyn Tr, &(dyn Tr + Send), &(dyn Tr + Send + Sync)) = (&0u8, &1u8, &2u8);
+
+// Coercing the same type to the same trait object repeatedly during one evaluation only
+// allocates its vtable once.
+const _: () = {
+    let mut i = 0u8;
+    while i < 100 {
+        let _: &dyn Tr = &i;
+        i += 1;
+    }
+};
+
+fn main() {}
@@ -0,0 +1,34 @@
+// run-pass
+
+#![feature(destructuring_assignment)]
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+struct TupleStruct<S, T>(S, T);
+
+fn main() {
+    let (mut a, mut b, mut c, mut x, mut y);
+
+    (a, Point { x, y }) = (1, Point { x: 2, y: 3 });
+    assert_eq!((a, x, y), (1, 2, 3));
+
+    TupleStruct([a, b], c) = TupleStruct([4, 5], 6);
+    assert_eq!((a, b, c), (4, 5, 6));
+
+    // A slice pattern inside a tuple inside a tuple struct.
+    TupleStruct((a, [b, .., c]), Point { x, y: _ }) =
+        TupleStruct((7, [8, 0, 0, 9]), Point { x: 10, y: 0 });
+    assert_eq!((a, b, c, x), (7, 8, 9, 10));
+
+    // A brace struct inside a slice inside a tuple.
+    (a, [Point { x, y }, _]) = (11, [Point { x: 12, y: 13 }, Point { x: 0, y: 0 }]);
+    assert_eq!((a, x, y), (11, 12, 13));
+
+    // Three levels of nesting in every position.
+    (TupleStruct([a, ..], Point { x: b, .. }), [(c, _)], Point { x, y }) =
+        (TupleStruct([14, 0], Point { x: 15, y: 0 }), [(16, 0)], Point { x: 17, y: 18 });
+    assert_eq!((a, b, c, x, y), (14, 15, 16, 17, 18));
+}
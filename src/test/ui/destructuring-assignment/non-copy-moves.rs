@@ -0,0 +1,52 @@
+// run-pass
+
+//! Test that destructuring assignment moves non-`Copy` values into the assigned places and
+//! drops the values that were there before, exactly like an ordinary assignment.
+
+#![feature(destructuring_assignment)]
+
+use std::cell::RefCell;
+
+thread_local! {
+    static DROPPED: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+struct Tracked(String);
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DROPPED.with(|d| d.borrow_mut().push(self.0.clone()));
+    }
+}
+
+fn tracked(name: &str) -> Tracked {
+    Tracked(name.to_owned())
+}
+
+fn take_dropped() -> Vec<String> {
+    DROPPED.with(|d| d.borrow_mut().drain(..).collect())
+}
+
+fn main() {
+    let mut a = tracked("a");
+    let mut b = tracked("b");
+
+    // The old values of `a` and `b` are dropped, the new ones are moved in.
+    (a, b) = (tracked("c"), tracked("d"));
+    assert_eq!(take_dropped(), ["a", "b"]);
+    assert_eq!((&*a.0, &*b.0), ("c", "d"));
+
+    // Swapping moves both values out before moving them back in, so nothing is dropped.
+    (a, b) = (b, a);
+    assert!(take_dropped().is_empty());
+    assert_eq!((&*a.0, &*b.0), ("d", "c"));
+
+    // Values that are moved out of the right-hand side are not dropped twice, and discarded
+    // values are dropped at the end of the destructuring, before the old value of `a`.
+    let e = tracked("e");
+    (a, _) = (e, tracked("f"));
+    assert_eq!(take_dropped(), ["f", "d"]);
+    assert_eq!(&*a.0, "e");
+
+    drop((a, b));
+    assert_eq!(take_dropped(), ["e", "c"]);
+}
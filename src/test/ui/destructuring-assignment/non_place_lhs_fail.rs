@@ -0,0 +1,24 @@
+#![feature(destructuring_assignment)]
+#![allow(unused)]
+
+struct TupleStruct<S, T>(S, T);
+
+struct Struct<S, T> {
+    a: S,
+    b: T,
+}
+
+fn main() {
+    let (mut a, mut b) = (0, 0);
+    let v = vec![0];
+    TupleStruct(a + 1, b) = TupleStruct(0, 1);
+    //~^ ERROR invalid left-hand side of destructuring assignment
+    (a, v.len()) = (2, 3);
+    //~^ ERROR invalid left-hand side of destructuring assignment
+    [1, b] = [4, 5];
+    //~^ ERROR invalid left-hand side of destructuring assignment
+    Struct { a: 6, b } = Struct { a: 6, b: 7 };
+    //~^ ERROR invalid left-hand side of destructuring assignment
+    ((a, -b), _) = ((8, 9), 10);
+    //~^ ERROR invalid left-hand side of destructuring assignment
+}
@@ -0,0 +1,41 @@
+// run-pass
+
+//! Test that the right-hand side of a destructuring assignment is evaluated exactly once.
+
+#![feature(destructuring_assignment)]
+
+use std::cell::Cell;
+
+struct TupleStruct<S, T, U>(S, T, U);
+
+struct Struct {
+    x: i32,
+    y: i32,
+}
+
+fn expensive<T>(count: &Cell<u32>, value: T) -> T {
+    count.set(count.get() + 1);
+    value
+}
+
+fn main() {
+    let count = Cell::new(0);
+    let (mut a, mut b, mut c);
+
+    TupleStruct(a, b, c) = expensive(&count, TupleStruct(1, 2, 3));
+    assert_eq!((a, b, c), (1, 2, 3));
+    assert_eq!(count.get(), 1);
+
+    (a, (b, c)) = expensive(&count, (4, (5, 6)));
+    assert_eq!((a, b, c), (4, 5, 6));
+    assert_eq!(count.get(), 2);
+
+    [a, .., c] = expensive(&count, [7, 8, 9, 10]);
+    assert_eq!((a, c), (7, 10));
+    assert_eq!(count.get(), 3);
+
+    (Struct { x: a, y: b }, TupleStruct(_, c, _)) =
+        expensive(&count, (Struct { x: 11, y: 12 }, TupleStruct(0, 13, 0)));
+    assert_eq!((a, b, c), (11, 12, 13));
+    assert_eq!(count.get(), 4);
+}
@@ -0,0 +1,23 @@
+// run-pass
+
+#![feature(destructuring_assignment)]
+
+fn main() {
+  let (mut first, mut last);
+  let arr = [1, 2, 3, 4];
+  [first, .., last] = arr;
+  assert_eq!((first, last), (1, 4));
+  // `..` matching no elements at all.
+  [first, .., last] = [5, 6];
+  assert_eq!((first, last), (5, 6));
+  [first, last, ..] = [7, 8];
+  assert_eq!((first, last), (7, 8));
+  [.., first, last] = [9, 10];
+  assert_eq!((first, last), (9, 10));
+  // Nested arrays.
+  [[first, ..], [.., last]] = [[11, 12], [13, 14]];
+  assert_eq!((first, last), (11, 14));
+  let mut middle;
+  [_, middle, ..] = arr;
+  assert_eq!(middle, 2);
+}
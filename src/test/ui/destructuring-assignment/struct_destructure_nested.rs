@@ -0,0 +1,48 @@
+// run-pass
+
+#![feature(destructuring_assignment)]
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+struct Wrapper {
+    inner: Point,
+    tag: u8,
+}
+
+struct Many {
+    a: i32,
+    b: i32,
+    c: i32,
+    d: i32,
+}
+
+fn make(count: &mut u32) -> Many {
+    *count += 1;
+    Many { a: 1, b: 2, c: 3, d: 4 }
+}
+
+fn main() {
+    let (mut x, mut y, mut tag);
+    Wrapper { inner: Point { x, y }, tag } = Wrapper { inner: Point { x: 1, y: 2 }, tag: 3 };
+    assert_eq!((x, y, tag), (1, 2, 3));
+    Wrapper { inner: Point { x: y, y: x }, .. } =
+        Wrapper { inner: Point { x: 4, y: 5 }, tag: 6 };
+    assert_eq!((x, y, tag), (5, 4, 3));
+    Wrapper { inner: Point { x, .. }, tag: _ } = Wrapper { inner: Point { x: 7, y: 8 }, tag: 9 };
+    assert_eq!((x, y, tag), (7, 4, 3));
+    (Point { x, y }, tag) = (Point { x: 10, y: 11 }, 12);
+    assert_eq!((x, y, tag), (10, 11, 12));
+
+    // The right-hand side is only evaluated once, however many fields are assigned.
+    let mut count = 0;
+    let (mut a, mut b, mut c, mut d);
+    Many { a, b, c, d } = make(&mut count);
+    assert_eq!((a, b, c, d), (1, 2, 3, 4));
+    assert_eq!(count, 1);
+    Many { d: a, c: b, .. } = make(&mut count);
+    assert_eq!((a, b, c, d), (4, 3, 3, 4));
+    assert_eq!(count, 2);
+}
@@ -0,0 +1,11 @@
+// Check that assigning a tuple to a tuple pattern with a different number of elements reports
+// both lengths, pointing at the pattern and at the assigned expression.
+#![feature(destructuring_assignment)]
+
+fn main() {
+    let (mut a, mut b, mut c);
+    (a, b) = (1, 2, 3); //~ ERROR mismatched types
+    (a, b, c) = (1, 2); //~ ERROR mismatched types
+    (a, b) = (1,); //~ ERROR mismatched types
+    (a, (b, c)) = (1, (2, 3, 4)); //~ ERROR mismatched types
+}
@@ -0,0 +1,15 @@
+#![feature(destructuring_assignment)]
+
+struct TupleStruct<S, T>(S, T);
+
+fn main() {
+    let (mut a, mut b, mut c);
+    TupleStruct(a, b, c, a) = TupleStruct(0, 1);
+    //~^ ERROR this tuple struct has 2 fields, but the pattern has 4
+    (a, TupleStruct(b, c, _)) = (0, TupleStruct(1, 2));
+    //~^ ERROR this tuple struct has 2 fields, but the pattern has 3
+    [TupleStruct(a), _] = [TupleStruct(0, 1), TupleStruct(2, 3)];
+    //~^ ERROR this tuple struct has 2 fields, but the pattern has 1
+    TupleStruct() = TupleStruct(0, 1);
+    //~^ ERROR this tuple struct has 2 fields, but the pattern has 0
+}
@@ -28,13 +28,13 @@ fn main() {
     //~^ ERROR `..` can only be used once per tuple struct or variant pattern
 
     TupleStruct(a, a, b) = TupleStruct(1, 2);
-    //~^ ERROR this pattern has 3 fields, but the corresponding tuple struct has 2 fields
+    //~^ ERROR this tuple struct has 2 fields, but the pattern has 3
     TupleStruct(_) = TupleStruct(1, 2);
-    //~^ ERROR this pattern has 1 field, but the corresponding tuple struct has 2 fields
+    //~^ ERROR this tuple struct has 2 fields, but the pattern has 1
     Enum::SingleVariant(a, a, b) = Enum::SingleVariant(1, 2);
-    //~^ ERROR this pattern has 3 fields, but the corresponding tuple variant has 2 fields
+    //~^ ERROR this tuple variant has 2 fields, but the pattern has 3
     Enum::SingleVariant(_) = Enum::SingleVariant(1, 2);
-    //~^ ERROR this pattern has 1 field, but the corresponding tuple variant has 2 fields
+    //~^ ERROR this tuple variant has 2 fields, but the pattern has 1
 
     // Check if `test` is recognized as not a tuple struct but a function call:
     test() = TupleStruct(0, 0);
@@ -0,0 +1,47 @@
+// run-pass
+
+//! Test that values discarded with `_` in a destructuring assignment are dropped exactly once.
+
+#![feature(destructuring_assignment)]
+
+use std::cell::Cell;
+
+thread_local! {
+    static DROPS: Cell<usize> = Cell::new(0);
+}
+
+struct Counted(i32);
+impl Drop for Counted {
+    fn drop(&mut self) {
+        DROPS.with(|d| d.set(d.get() + 1));
+    }
+}
+
+struct TupleStruct<S, T>(S, T);
+
+fn drops() -> usize {
+    DROPS.with(|d| d.get())
+}
+
+fn main() {
+    let (mut a, mut b, mut c);
+    (a, _, c) = (1, 2, 3);
+    assert_eq!((a, c), (1, 3));
+    TupleStruct(_, b) = TupleStruct(0, 1);
+    assert_eq!(b, 1);
+
+    (a, _, c) = (4, Counted(5), 6);
+    assert_eq!((a, c), (4, 6));
+    assert_eq!(drops(), 1);
+
+    TupleStruct(_, b) = TupleStruct(Counted(7), 8);
+    assert_eq!(b, 8);
+    assert_eq!(drops(), 2);
+
+    let kept;
+    (kept, _) = (Counted(9), Counted(10));
+    assert_eq!(drops(), 3);
+    assert_eq!(kept.0, 9);
+    drop(kept);
+    assert_eq!(drops(), 4);
+}
@@ -0,0 +1,18 @@
+// run-pass
+
+#![feature(destructuring_assignment)]
+
+fn f(calls: &mut u32) {
+    *calls += 1;
+}
+
+fn main() {
+    let mut calls = 0;
+    () = f(&mut calls);
+    assert_eq!(calls, 1);
+    (()) = f(&mut calls);
+    assert_eq!(calls, 2);
+    ((), ()) = (f(&mut calls), f(&mut calls));
+    assert_eq!(calls, 4);
+    () = ();
+}
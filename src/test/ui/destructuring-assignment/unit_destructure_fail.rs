@@ -0,0 +1,5 @@
+#![feature(destructuring_assignment)]
+
+fn main() {
+    () = 5; //~ ERROR mismatched types
+}
@@ -0,0 +1,13 @@
+#![feature(min_const_generics)]
+
+pub struct Wrapper<const N: usize>;
+
+impl<const N: usize> Wrapper<N> {
+    pub fn len(&self) -> usize {
+        N
+    }
+}
+
+pub fn len<const N: usize>() -> usize {
+    N
+}
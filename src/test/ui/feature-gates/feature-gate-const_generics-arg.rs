@@ -0,0 +1,15 @@
+// check-pass
+// aux-build:const_generic_lib.rs
+// Const arguments are not feature gated: only declaring a const parameter is, so that crates can
+// use const generic items from their dependencies.
+
+extern crate const_generic_lib;
+
+use const_generic_lib::{len, Wrapper};
+
+fn main() {
+    let w: Wrapper<3> = Wrapper;
+    assert_eq!(w.len(), 3);
+    assert_eq!(Wrapper::<{ 2 + 2 }>.len(), 4);
+    assert_eq!(len::<5>(), 5);
+}
@@ -0,0 +1,9 @@
+struct Foo<T>(T);
+
+impl<const N: usize> Foo<[u8; N]> {} //~ ERROR const generics are unstable
+
+trait Bar {
+    fn bar<const M: u8>(); //~ ERROR const generics are unstable
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#![feature(min_const_generics)]
+
+struct A<const N: usize = 3>([u8; N]);
+//~^ ERROR default values for const generic parameters are experimental
+
+fn main() {}
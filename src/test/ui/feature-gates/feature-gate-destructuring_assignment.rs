@@ -1,4 +1,10 @@
+struct Struct { x: u8, y: u8 }
+struct TupleStruct(u8, u8);
+
 fn main() {
     let (a, b) = (0, 1);
     (a, b) = (2, 3); //~ ERROR destructuring assignments are unstable
+    [a, b] = [2, 3]; //~ ERROR destructuring assignments are unstable
+    TupleStruct(a, b) = TupleStruct(2, 3); //~ ERROR destructuring assignments are unstable
+    Struct { x: a, y: b } = Struct { x: 2, y: 3 }; //~ ERROR destructuring assignments are unstable
 }
@@ -1,8 +1,7 @@
 fn main() {
     let value = [7u8];
     while Some(0) = value.get(0) { //~ ERROR destructuring assignments are unstable
-        //~| ERROR invalid left-hand side of assignment
-        //~| ERROR mismatched types
+        //~| ERROR invalid left-hand side of destructuring assignment
         //~| ERROR mismatched types
 
         // FIXME The following diagnostic should also be emitted
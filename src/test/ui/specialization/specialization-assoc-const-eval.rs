@@ -0,0 +1,47 @@
+// run-pass
+// Check that const-eval uses the value of an associated const from the most specific impl, both
+// when the const is named directly and when it is reached through a generic `const fn`.
+
+#![feature(specialization)]
+#![allow(incomplete_features)]
+
+trait Size {
+    const SIZE: usize;
+}
+
+impl<T> Size for T {
+    default const SIZE: usize = 1;
+}
+
+impl Size for u32 {
+    const SIZE: usize = 4;
+}
+
+impl<T> Size for Vec<T> {
+    default const SIZE: usize = 24;
+}
+
+impl Size for Vec<u8> {
+    const SIZE: usize = 3;
+}
+
+const fn size_of<T>() -> usize {
+    T::SIZE
+}
+
+const U32: usize = <u32 as Size>::SIZE;
+const I32: usize = <i32 as Size>::SIZE;
+const VEC_U8: usize = size_of::<Vec<u8>>();
+const VEC_U16: usize = size_of::<Vec<u16>>();
+
+fn main() {
+    assert_eq!(U32, 4);
+    assert_eq!(I32, 1);
+    assert_eq!(VEC_U8, 3);
+    assert_eq!(VEC_U16, 24);
+
+    let array = [0u8; <u32 as Size>::SIZE];
+    assert_eq!(array.len(), 4);
+    let array = [0u8; size_of::<Vec<u8>>()];
+    assert_eq!(array.len(), 3);
+}
@@ -9,5 +9,5 @@ fn main() {
     if 3 = foo {} //~ ERROR mismatched types
     if Some(3) = foo {} //~ ERROR mismatched types
     //~^ ERROR destructuring assignments are unstable
-    //~^^ ERROR invalid left-hand side of assignment
+    //~^^ ERROR invalid left-hand side of destructuring assignment
 }
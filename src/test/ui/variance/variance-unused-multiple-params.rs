@@ -0,0 +1,10 @@
+// Test that we report every unused parameter of a type, in declaration order.
+
+struct Unused<'a, 'b, T> {
+    //~^ ERROR parameter `'a` is never used
+    //~| ERROR parameter `'b` is never used
+    //~| ERROR parameter `T` is never used
+    x: u32,
+}
+
+fn main() {}
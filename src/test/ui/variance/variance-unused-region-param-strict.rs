@@ -0,0 +1,10 @@
+// Test that `-Z strict-unused-lifetimes` also reports unused lifetime parameters of traits.
+// compile-flags: -Z strict-unused-lifetimes
+
+trait SomeTrait<'a> { fn foo(&self); } //~ ERROR parameter `'a` is never used
+trait UsedInMethod<'a> { fn foo(&self, x: &'a u8); }
+trait UsedInBound<'a>: Iterator<Item = &'a u8> {}
+trait UsedInAssocTy<'a> { type T: 'a; }
+trait PartlyUsed<'a, 'b> { fn foo(&self) -> &'a u8; } //~ ERROR parameter `'b` is never used
+
+fn main() {}
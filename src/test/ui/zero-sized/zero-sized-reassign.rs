@@ -0,0 +1,38 @@
+// run-pass
+#![allow(unused_assignments)]
+
+// Make sure that reassigning a local of zero-sized type works, both with constants and with
+// values whose type is only known to be zero-sized after monomorphization.
+
+use std::cell::Cell;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Unit;
+
+fn assign_twice<T: Copy>(a: T, b: T) -> T {
+    let mut x = a;
+    x = b;
+    x
+}
+
+fn assign_twice_with<T>(make: impl Fn() -> T) -> T {
+    let mut x = make();
+    x = make();
+    x
+}
+
+fn main() {
+    let mut u = Unit;
+    u = Unit;
+    assert_eq!(u, Unit);
+
+    assert_eq!(assign_twice(Unit, Unit), Unit);
+    assert_eq!(assign_twice((), ()), ());
+    assert_eq!(assign_twice([0u8; 0], []), []);
+    assert_eq!(assign_twice(1, 2), 2);
+
+    let count = Cell::new(0);
+    let unit = assign_twice_with(|| count.set(count.get() + 1));
+    assert_eq!(unit, ());
+    assert_eq!(count.get(), 2);
+}